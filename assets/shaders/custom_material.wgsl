@@ -2,13 +2,44 @@
 #import bevy_pbr::mesh_functions::mesh_position_local_to_clip
 #import bevy_pbr::mesh_functions::mesh_position_local_to_world
 #import bevy_pbr::mesh_functions::mesh_normal_local_to_world
+#import bevy_pbr::mesh_functions::mesh_tangent_local_to_world
 #import bevy_pbr::forward_io::Vertex
 #import bevy_pbr::forward_io::VertexOutput
+#import bevy_pbr::mesh_view_bindings::view
+#import bevy_pbr::pbr_functions
+#import bevy_pbr::pbr_types
+#import bevy_render::maths::PI_2
+#import "shaders/shader_time.wgsl"::shader_time
 
-@group(2) @binding(0) var<uniform> material_color: vec4<f32>;
-@group(2) @binding(1) var<uniform> time_secs: f32;
-@group(2) @binding(2) var material_color_texture: texture_2d<f32>;
-@group(2) @binding(3) var material_color_sampler: sampler;
+struct MaterialTime {
+    scale: f32,
+    offset: f32,
+};
+
+struct Hologram {
+    rim_color: vec4<f32>,
+    rim_power: f32,
+    scanline_density: f32,
+    scroll_speed: f32,
+    flicker: f32,
+};
+
+struct CustomMaterial {
+    color: vec4<f32>,
+    emissive: vec4<f32>,
+    hologram: Hologram,
+    time: MaterialTime,
+};
+
+@group(2) @binding(0) var<uniform> material: CustomMaterial;
+@group(2) @binding(1) var material_color_texture: texture_2d<f32>;
+@group(2) @binding(2) var material_color_sampler: sampler;
+@group(2) @binding(3) var material_normal_map_texture: texture_2d<f32>;
+@group(2) @binding(4) var material_normal_map_sampler: sampler;
+
+fn material_time_secs() -> f32 {
+    return shader_time().elapsed_secs * material.time.scale + material.time.offset;
+}
 
 @vertex
 fn vertex(vertex: Vertex) -> VertexOutput {
@@ -20,10 +51,80 @@ fn vertex(vertex: Vertex) -> VertexOutput {
     out.world_position = mesh_position_local_to_world(world, vertex_position);
     out.world_normal = mesh_normal_local_to_world(vertex.normal, vertex.instance_index);
     out.uv = vertex.uv;
+#ifdef VERTEX_TANGENTS
+    out.world_tangent = mesh_tangent_local_to_world(world, vertex.tangent, vertex.instance_index);
+#endif
+#ifdef VERTEX_COLORS
+    out.color = vertex.color;
+#endif
     return out;
 }
 
+fn lit(mesh: VertexOutput, is_front: bool, color: vec4<f32>) -> vec4<f32> {
+    var pbr_input = pbr_types::pbr_input_new();
+    pbr_input.material.base_color = color;
+    pbr_input.material.emissive = vec4<f32>(material.emissive.rgb, 1.0);
+    pbr_input.frag_coord = mesh.position;
+    pbr_input.world_position = mesh.world_position;
+#ifdef DOUBLE_SIDED
+    let double_sided = true;
+#else
+    let double_sided = false;
+#endif
+    pbr_input.world_normal = pbr_functions::prepare_world_normal(mesh.world_normal, double_sided, is_front);
+    pbr_input.is_orthographic = view.clip_from_view[3].w == 1.0;
+    pbr_input.N = normalize(pbr_input.world_normal);
+#ifdef NORMAL_MAP
+#ifdef VERTEX_TANGENTS
+    let TBN = pbr_functions::calculate_tbn_mikktspace(pbr_input.world_normal, mesh.world_tangent);
+    let Nt = textureSample(material_normal_map_texture, material_normal_map_sampler, mesh.uv).rgb;
+    pbr_input.N = pbr_functions::apply_normal_mapping(0u, TBN, double_sided, is_front, Nt);
+#endif
+#endif
+    pbr_input.V = pbr_functions::calculate_view(mesh.world_position, pbr_input.is_orthographic);
+
+    let lit_color = pbr_functions::apply_pbr_lighting(pbr_input);
+    return pbr_functions::main_pass_post_lighting_processing(pbr_input, lit_color);
+}
+
+fn hologram(mesh: VertexOutput, color: vec4<f32>) -> vec4<f32> {
+    let time = material_time_secs();
+    let to_view = normalize(view.world_position - mesh.world_position.xyz);
+    let facing = abs(dot(normalize(mesh.world_normal), to_view));
+    let rim = pow(1.0 - saturate(facing), material.hologram.rim_power);
+
+    let scan_position = (mesh.world_position.y - time * material.hologram.scroll_speed) * material.hologram.scanline_density;
+    let scanline = 0.5 + 0.5 * sin(scan_position * PI_2);
+
+    let noise = sin(time * 37.0) * sin(time * 23.0 + 1.3);
+    let flicker = 1.0 - material.hologram.flicker * (0.5 + 0.5 * noise);
+
+    let rgb = color.rgb * scanline + material.hologram.rim_color.rgb * rim;
+    let alpha = saturate(color.a * scanline + material.hologram.rim_color.a * rim);
+    return vec4<f32>(rgb, alpha) * flicker;
+}
+
 @fragment
-fn fragment(mesh: VertexOutput) -> @location(0) vec4<f32> {
-    return material_color * textureSample(material_color_texture, material_color_sampler, mesh.uv);
+fn fragment(
+    mesh: VertexOutput,
+    @builtin(front_facing) is_front: bool,
+) -> @location(0) vec4<f32> {
+    var color = material.color;
+#ifdef COLOR_TEXTURE
+    color *= textureSample(material_color_texture, material_color_sampler, mesh.uv);
+#endif
+#ifdef MATERIAL_VERTEX_COLORS
+#ifdef VERTEX_COLORS
+    color *= mesh.color;
+#endif
+#endif
+#ifdef LIT
+    var output = lit(mesh, is_front, color);
+#else
+    var output = color + vec4<f32>(material.emissive.rgb, 0.0);
+#endif
+#ifdef HOLOGRAM
+    output = hologram(mesh, output);
+#endif
+    return output;
 }
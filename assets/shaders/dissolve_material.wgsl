@@ -0,0 +1,34 @@
+#import bevy_pbr::forward_io::VertexOutput
+
+struct Dissolve {
+    threshold: f32,
+    edge_width: f32,
+    edge_color: vec4<f32>,
+};
+
+@group(2) @binding(0) var<uniform> material_color: vec4<f32>;
+@group(2) @binding(1) var material_color_texture: texture_2d<f32>;
+@group(2) @binding(2) var material_color_sampler: sampler;
+@group(2) @binding(3) var material_noise_texture: texture_2d<f32>;
+@group(2) @binding(4) var material_noise_sampler: sampler;
+@group(2) @binding(5) var<uniform> material_dissolve: Dissolve;
+@group(2) @binding(6) var<uniform> material_alpha_cutoff: f32;
+
+@fragment
+fn fragment(mesh: VertexOutput) -> @location(0) vec4<f32> {
+    let noise = textureSample(material_noise_texture, material_noise_sampler, mesh.uv).r;
+    let threshold = material_dissolve.threshold;
+
+    if noise < threshold || threshold >= 1.0 {
+        discard;
+    }
+
+    let color = material_color * textureSample(material_color_texture, material_color_sampler, mesh.uv);
+    if color.a < material_alpha_cutoff {
+        discard;
+    }
+
+    // no edge while fully solid
+    let edge = select(0.0, 1.0 - smoothstep(threshold, threshold + material_dissolve.edge_width, noise), threshold > 0.0);
+    return vec4<f32>(color.rgb + material_dissolve.edge_color.rgb * edge, color.a);
+}
@@ -0,0 +1,17 @@
+struct ShaderTime {
+    // Seconds since startup, wrapping to 0 after one hour
+    elapsed_secs: f32,
+    delta_secs: f32,
+    // Frames since startup, wrapping to 0 after the maximum u32 value
+    frame_count: u32,
+    sin_time: f32,
+    cos_time: f32,
+};
+
+// Written from the `ShaderTime` resource, bound by every crate material at
+// `SHADER_TIME_BINDING`
+@group(2) @binding(100) var<uniform> shader_time_uniform: ShaderTime;
+
+fn shader_time() -> ShaderTime {
+    return shader_time_uniform;
+}
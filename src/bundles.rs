@@ -1,13 +1,32 @@
-use crate::components::ReplacementMaterial;
+use crate::{
+	components::{ReplacementMaterial, ReplacementRules},
+	material::CustomMaterial,
+};
 use bevy::prelude::*;
 
-#[derive(Bundle, Default)]
-pub struct MaterialAssetBundle {
+#[derive(Bundle)]
+pub struct MaterialAssetBundle<TMaterial: Material = CustomMaterial> {
 	pub asset: Handle<Scene>,
-	pub material: ReplacementMaterial,
+	pub material: ReplacementMaterial<TMaterial>,
+	pub rules: ReplacementRules<TMaterial>,
 	pub transform: Transform,
 	pub global_transform: GlobalTransform,
 	pub visibility: Visibility,
 	pub inherited_visibility: InheritedVisibility,
 	pub view_visibility: ViewVisibility,
 }
+
+impl<TMaterial: Material> Default for MaterialAssetBundle<TMaterial> {
+	fn default() -> Self {
+		Self {
+			asset: default(),
+			material: default(),
+			rules: default(),
+			transform: default(),
+			global_transform: default(),
+			visibility: default(),
+			inherited_visibility: default(),
+			view_visibility: default(),
+		}
+	}
+}
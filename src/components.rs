@@ -1,5 +1,867 @@
-use crate::material::CustomMaterial;
-use bevy::prelude::*;
+use crate::{material::CustomMaterial, traits::lerp::Lerp};
+use bevy::{prelude::*, render::primitives::Aabb};
+use serde::{Deserialize, Serialize};
+use std::fmt::{self, Debug, Formatter};
 
-#[derive(Component, Default)]
-pub struct ReplacementMaterial(pub Handle<CustomMaterial>);
+#[derive(Component)]
+pub struct ReplacementMaterial<TMaterial: Material = CustomMaterial>(pub Handle<TMaterial>);
+
+impl<TMaterial: Material> Default for ReplacementMaterial<TMaterial> {
+	fn default() -> Self {
+		Self(Handle::default())
+	}
+}
+
+/// Gives all [`CustomMaterial`]s of this entity and its descendants their own material
+/// instance with adjusted animation and color.
+///
+/// The instance time is `elapsed_secs * time_scale + time_offset` of the
+/// [`ShaderTime`](crate::resources::ShaderTime). Pausing freezes it at the time of pausing.
+#[derive(Component, Debug, PartialEq, Clone, Copy)]
+pub struct MaterialOverrides {
+	pub time_offset: f32,
+	pub time_scale: f32,
+	pub paused: bool,
+	pub tint: LinearRgba,
+}
+
+impl Default for MaterialOverrides {
+	fn default() -> Self {
+		Self {
+			time_offset: 0.,
+			time_scale: 1.,
+			paused: false,
+			tint: LinearRgba::WHITE,
+		}
+	}
+}
+
+/// Marks a per-entity copy of the shared `base` material created for [`MaterialOverrides`].
+#[derive(Component, Debug, PartialEq, Clone)]
+pub struct MaterialInstance {
+	pub base: Handle<CustomMaterial>,
+	pub overrides: MaterialOverrides,
+	/// The frozen instance time while paused
+	pub paused_secs: Option<f32>,
+}
+
+/// The point a camera orbits around and follows. Cameras without one orbit around the
+/// [`OrbitTarget::default`] and get it inserted once that is moved.
+#[derive(Component, Debug, PartialEq, Clone, Copy)]
+pub struct OrbitTarget {
+	pub anchor: OrbitAnchor,
+	pub offset: Vec3,
+}
+
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum OrbitAnchor {
+	Point(Vec3),
+	/// The global translation of the entity
+	Entity(Entity),
+}
+
+impl OrbitTarget {
+	pub fn point(point: Vec3) -> Self {
+		Self {
+			anchor: OrbitAnchor::Point(point),
+			offset: Vec3::ZERO,
+		}
+	}
+
+	pub fn entity(entity: Entity) -> Self {
+		Self {
+			anchor: OrbitAnchor::Entity(entity),
+			offset: Vec3::ZERO,
+		}
+	}
+
+	pub fn with_offset(self, offset: Vec3) -> Self {
+		Self { offset, ..self }
+	}
+
+	/// Returns `None` when the anchor entity has no [`GlobalTransform`].
+	pub fn position(&self, transforms: &Query<&GlobalTransform>) -> Option<Vec3> {
+		let anchor = match self.anchor {
+			OrbitAnchor::Point(point) => point,
+			OrbitAnchor::Entity(entity) => transforms.get(entity).ok()?.translation(),
+		};
+
+		Some(anchor + self.offset)
+	}
+
+	/// Moves the target to `position`, keeping the kind of its anchor. Points are moved,
+	/// entity anchors get their offset adjusted and stay unchanged without a position.
+	pub fn move_to(&mut self, position: Vec3, transforms: &Query<&GlobalTransform>) {
+		match self.anchor {
+			OrbitAnchor::Point(_) => self.anchor = OrbitAnchor::Point(position - self.offset),
+			OrbitAnchor::Entity(entity) => {
+				if let Ok(anchor) = transforms.get(entity) {
+					self.offset = position - anchor.translation();
+				}
+			}
+		}
+	}
+}
+
+impl Default for OrbitTarget {
+	fn default() -> Self {
+		Self::point(Vec3::new(0., 0.5, 0.))
+	}
+}
+
+/// Eases the camera towards the distance requested by mouse wheel input instead of
+/// jumping there.
+#[derive(Component, Debug, PartialEq, Clone, Copy)]
+pub struct SmoothZoom {
+	/// Exponential decay rate of the remaining distance, per second
+	pub rate: f32,
+	pub target_distance: Option<f32>,
+}
+
+impl SmoothZoom {
+	pub fn new(rate: f32) -> Self {
+		Self {
+			rate,
+			target_distance: None,
+		}
+	}
+}
+
+/// Keeps the camera orbiting after mouse rotation stops, slowing down over time.
+#[derive(Component, Debug, PartialEq, Clone, Copy)]
+pub struct OrbitInertia {
+	/// Exponential decay rate of the velocity, per second
+	pub damping: f32,
+	/// Yaw and pitch speed in radians per second
+	pub velocity: Vec2,
+}
+
+impl OrbitInertia {
+	pub fn new(damping: f32) -> Self {
+		Self {
+			damping,
+			velocity: Vec2::ZERO,
+		}
+	}
+}
+
+/// Orbit anchor and distance a camera looks at something from.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct Focus {
+	pub anchor: Vec3,
+	pub distance: f32,
+}
+
+impl Focus {
+	/// Anchors at the center of `bounds` at the distance its bounding sphere, enlarged by
+	/// `padding`, fills the viewport of a perspective with vertical `fov` in radians.
+	pub fn framing(bounds: &Aabb, fov: f32, aspect_ratio: f32, padding: f32) -> Self {
+		let half_fov = fov / 2.;
+		let half_horizontal_fov = (half_fov.tan() * aspect_ratio).atan();
+		let radius = bounds.half_extents.length() * padding;
+
+		Self {
+			anchor: Vec3::from(bounds.center),
+			distance: radius / half_fov.min(half_horizontal_fov).sin(),
+		}
+	}
+}
+
+impl Lerp for Focus {
+	fn lerp(&self, other: &Self, t: f32) -> Self {
+		Self {
+			anchor: self.anchor.lerp(other.anchor, t),
+			distance: self.distance.lerp(other.distance, t),
+		}
+	}
+}
+
+/// A camera viewpoint, see [`CameraBookmarks`](crate::resources::CameraBookmarks).
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone, Copy)]
+pub struct CameraBookmark {
+	pub translation: Vec3,
+	pub rotation: Quat,
+	pub anchor: Vec3,
+	pub distance: f32,
+}
+
+impl CameraBookmark {
+	pub fn new(transform: &Transform, anchor: Vec3) -> Self {
+		Self {
+			translation: transform.translation,
+			rotation: transform.rotation,
+			anchor,
+			distance: (transform.translation - anchor).length(),
+		}
+	}
+}
+
+impl Lerp for CameraBookmark {
+	/// Interpolates anchor, distance and rotation, placing the camera on its orbit around
+	/// the interpolated anchor instead of cutting straight through it.
+	fn lerp(&self, other: &Self, t: f32) -> Self {
+		let anchor = self.anchor.lerp(other.anchor, t);
+		let distance = self.distance.lerp(other.distance, t);
+		let rotation = self.rotation.slerp(other.rotation, t);
+
+		Self {
+			translation: anchor + rotation * Vec3::Z * distance,
+			rotation,
+			anchor,
+			distance,
+		}
+	}
+}
+
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum Easing {
+	Linear,
+	/// Eases in and out
+	SmoothStep,
+}
+
+impl Easing {
+	fn apply(&self, t: f32) -> f32 {
+		match self {
+			Easing::Linear => t,
+			Easing::SmoothStep => t * t * (3. - 2. * t),
+		}
+	}
+}
+
+/// Moves a value from `from` to `to`, ending exactly at `to`.
+#[derive(Component, Debug, PartialEq, Clone, Copy)]
+pub struct Transition<T: Lerp + Copy + Send + Sync + 'static> {
+	pub from: T,
+	pub to: T,
+	/// In seconds
+	pub duration: f32,
+	/// In seconds
+	pub elapsed: f32,
+	pub easing: Easing,
+}
+
+impl<T: Lerp + Copy + Send + Sync + 'static> Transition<T> {
+	/// Eases in and out, use [`Transition::with_easing`] for other easings.
+	pub fn new(from: T, to: T, duration: f32) -> Self {
+		Self {
+			from,
+			to,
+			duration,
+			elapsed: 0.,
+			easing: Easing::SmoothStep,
+		}
+	}
+
+	pub fn with_easing(self, easing: Easing) -> Self {
+		Self { easing, ..self }
+	}
+
+	/// The value at the current elapsed time.
+	pub fn value(&self) -> T {
+		if self.is_finished() {
+			return self.to;
+		}
+
+		self.from.lerp(&self.to, self.easing.apply(self.progress()))
+	}
+
+	pub fn is_finished(&self) -> bool {
+		self.progress() >= 1.
+	}
+
+	fn progress(&self) -> f32 {
+		if self.duration <= 0. {
+			return 1.;
+		}
+
+		(self.elapsed / self.duration).clamp(0., 1.)
+	}
+}
+
+/// Moves the camera from one [`Focus`] to another, inserted for
+/// [`FocusOn`](crate::events::FocusOn) events.
+pub type FocusTransition = Transition<Focus>;
+
+/// Moves the camera from its viewpoint to a recalled [`CameraBookmark`].
+pub type BookmarkTransition = Transition<CameraBookmark>;
+
+/// Moves the [`Dissolve::threshold`](crate::material::dissolve::Dissolve::threshold)
+/// of the entity's [`DissolveMaterial`](crate::material::dissolve_material::DissolveMaterial)
+/// along its transition.
+///
+/// The material asset is modified in place, so each dissolving entity needs its own.
+#[derive(Component, Debug, PartialEq, Clone, Copy)]
+pub struct DissolveTransition(pub Transition<f32>);
+
+impl DissolveTransition {
+	/// Moves the threshold linearly from `from` to `to`.
+	pub fn new(from: f32, to: f32, duration: f32) -> Self {
+		Self(Transition::new(from, to, duration).with_easing(Easing::Linear))
+	}
+
+	/// Materializes from fully dissolved to solid, for spawning.
+	pub fn appear(duration: f32) -> Self {
+		Self::new(1., 0., duration)
+	}
+
+	/// Dissolves from solid and despawns the entity afterwards.
+	pub fn disappear(duration: f32) -> (Self, DespawnDissolved) {
+		(Self::new(0., 1., duration), DespawnDissolved)
+	}
+}
+
+/// Despawns the entity and its descendants once its [`DissolveTransition`] finished.
+#[derive(Component, Debug, PartialEq, Default, Clone, Copy)]
+pub struct DespawnDissolved;
+
+/// The material a replacement was applied to, restored when the replacement is
+/// removed or changed.
+#[derive(Component)]
+pub struct OriginalMaterial<TMaterial: Material = StandardMaterial>(pub Handle<TMaterial>);
+
+impl<TMaterial: Material> PartialEq for OriginalMaterial<TMaterial> {
+	fn eq(&self, other: &Self) -> bool {
+		self.0 == other.0
+	}
+}
+
+impl<TMaterial: Material> Debug for OriginalMaterial<TMaterial> {
+	fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+		f.debug_tuple("OriginalMaterial").field(&self.0).finish()
+	}
+}
+
+/// Derives replacement materials from the replaced materials' properties instead of
+/// using the replacement materials as they are.
+///
+/// Base color, base color texture and alpha mode are always inherited, normal map and
+/// emissive only when enabled. Place it next to the [`ReplacementMaterial`] or
+/// [`ReplacementRules`] it applies to.
+#[derive(Component, Debug, PartialEq, Eq, Hash, Clone, Copy, Default)]
+pub struct InheritMaterialProperties {
+	pub normal_map: bool,
+	pub emissive: bool,
+}
+
+/// Picks replacement materials by name. The first matching rule wins.
+///
+/// Meshes matching no rule fall back to the [`ReplacementMaterial`] of the same entity,
+/// if present, then to replacements of outer ancestors, and are left untouched otherwise.
+#[derive(Component)]
+pub struct ReplacementRules<TMaterial: Material = CustomMaterial>(
+	pub Vec<ReplacementRule<TMaterial>>,
+);
+
+impl<TMaterial: Material> ReplacementRules<TMaterial> {
+	pub fn replacement_for(&self, names: &ReplacementNames) -> Option<&Handle<TMaterial>> {
+		self.0
+			.iter()
+			.find(|rule| rule.matches(names))
+			.map(|rule| &rule.material)
+	}
+}
+
+impl<TMaterial: Material> Default for ReplacementRules<TMaterial> {
+	fn default() -> Self {
+		Self(vec![])
+	}
+}
+
+pub struct ReplacementRule<TMaterial: Material = CustomMaterial> {
+	pub target: NameTarget,
+	pub pattern: NamePattern,
+	pub material: Handle<TMaterial>,
+}
+
+impl<TMaterial: Material> ReplacementRule<TMaterial> {
+	pub fn material_name(pattern: impl Into<NamePattern>, material: Handle<TMaterial>) -> Self {
+		Self {
+			target: NameTarget::Material,
+			pattern: pattern.into(),
+			material,
+		}
+	}
+
+	pub fn mesh_name(pattern: impl Into<NamePattern>, material: Handle<TMaterial>) -> Self {
+		Self {
+			target: NameTarget::Mesh,
+			pattern: pattern.into(),
+			material,
+		}
+	}
+
+	pub fn node_name(pattern: impl Into<NamePattern>, material: Handle<TMaterial>) -> Self {
+		Self {
+			target: NameTarget::Node,
+			pattern: pattern.into(),
+			material,
+		}
+	}
+
+	fn matches(&self, names: &ReplacementNames) -> bool {
+		match self.target {
+			NameTarget::Material => names.material.is_some_and(|n| self.pattern.matches(n)),
+			NameTarget::Mesh => names.mesh.is_some_and(|n| self.pattern.matches(n)),
+			NameTarget::Node => names.nodes.iter().any(|n| self.pattern.matches(n)),
+		}
+	}
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum NameTarget {
+	/// The glTF material name of the replaced material
+	Material,
+	/// The [`Name`] of the entity holding the replaced material
+	Mesh,
+	/// The [`Name`] of any ancestor between the mesh and the [`ReplacementRules`] holder
+	Node,
+}
+
+/// The names a [`ReplacementRule`] can match against.
+#[derive(Debug, PartialEq, Default, Clone)]
+pub struct ReplacementNames<'a> {
+	pub material: Option<&'a str>,
+	pub mesh: Option<&'a str>,
+	pub nodes: Vec<&'a str>,
+}
+
+/// Name pattern, where `*` matches any sequence of characters and `?` matches
+/// a single character.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct NamePattern(pub String);
+
+impl NamePattern {
+	pub fn matches(&self, name: &str) -> bool {
+		let pattern = self.0.chars().collect::<Vec<_>>();
+		let name = name.chars().collect::<Vec<_>>();
+		let (mut p, mut n) = (0, 0);
+		let mut last_wildcard = None;
+
+		while n < name.len() {
+			match pattern.get(p) {
+				Some('*') => {
+					last_wildcard = Some((p, n));
+					p += 1;
+				}
+				Some(c) if *c == '?' || *c == name[n] => {
+					p += 1;
+					n += 1;
+				}
+				_ => {
+					let Some((wildcard_p, wildcard_n)) = last_wildcard else {
+						return false;
+					};
+					last_wildcard = Some((wildcard_p, wildcard_n + 1));
+					p = wildcard_p + 1;
+					n = wildcard_n + 1;
+				}
+			}
+		}
+
+		pattern[p..].iter().all(|c| *c == '*')
+	}
+}
+
+impl From<&str> for NamePattern {
+	fn from(value: &str) -> Self {
+		Self(value.to_owned())
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::assert_approx_eq;
+	use bevy::ecs::system::SystemState;
+	use std::f32::consts::PI;
+
+	#[test]
+	fn pattern_matches_exact_name() {
+		assert!(NamePattern::from("Rim").matches("Rim"));
+	}
+
+	#[test]
+	fn pattern_does_not_match_other_name() {
+		assert!(!NamePattern::from("Rim").matches("Face"));
+	}
+
+	#[test]
+	fn pattern_does_not_match_prefix_only() {
+		assert!(!NamePattern::from("Rim").matches("RimLight"));
+	}
+
+	#[test]
+	fn pattern_matches_trailing_wildcard() {
+		assert!(NamePattern::from("Rim*").matches("RimLight"));
+	}
+
+	#[test]
+	fn pattern_matches_leading_wildcard() {
+		assert!(NamePattern::from("*Light").matches("RimLight"));
+	}
+
+	#[test]
+	fn pattern_matches_inner_wildcard() {
+		assert!(NamePattern::from("Shield*Face").matches("Shield.Front.Face"));
+	}
+
+	#[test]
+	fn pattern_matches_wildcard_with_backtracking() {
+		assert!(NamePattern::from("*.Face").matches("Shield.Face.Back.Face"));
+	}
+
+	#[test]
+	fn pattern_wildcard_matches_empty() {
+		assert!(NamePattern::from("Rim*").matches("Rim"));
+	}
+
+	#[test]
+	fn pattern_matches_single_character_wildcard() {
+		assert!(NamePattern::from("Mesh.?").matches("Mesh.1"));
+	}
+
+	#[test]
+	fn pattern_single_character_wildcard_does_not_match_empty() {
+		assert!(!NamePattern::from("Mesh.?").matches("Mesh."));
+	}
+
+	fn with_transforms<T>(world: &mut World, f: impl FnOnce(&Query<&GlobalTransform>) -> T) -> T {
+		let mut state = SystemState::<Query<&GlobalTransform>>::new(world);
+		f(&state.get(world))
+	}
+
+	#[test]
+	fn orbit_target_point_with_offset() {
+		let mut world = World::new();
+		let target = OrbitTarget::point(Vec3::new(1., 2., 3.)).with_offset(Vec3::Y);
+
+		let position = with_transforms(&mut world, |transforms| target.position(transforms));
+
+		assert_eq!(Some(Vec3::new(1., 3., 3.)), position);
+	}
+
+	#[test]
+	fn orbit_target_entity_with_offset() {
+		let mut world = World::new();
+		let entity = world.spawn(GlobalTransform::from_xyz(1., 2., 3.)).id();
+		let target = OrbitTarget::entity(entity).with_offset(Vec3::Y);
+
+		let position = with_transforms(&mut world, |transforms| target.position(transforms));
+
+		assert_eq!(Some(Vec3::new(1., 3., 3.)), position);
+	}
+
+	#[test]
+	fn orbit_target_entity_without_position() {
+		let mut world = World::new();
+		let target = OrbitTarget::entity(world.spawn_empty().id());
+
+		let position = with_transforms(&mut world, |transforms| target.position(transforms));
+
+		assert_eq!(None, position);
+	}
+
+	#[test]
+	fn move_orbit_target_point_keeping_offset() {
+		let mut world = World::new();
+		let mut target = OrbitTarget::point(Vec3::new(1., 2., 3.)).with_offset(Vec3::Y);
+
+		with_transforms(&mut world, |transforms| {
+			target.move_to(Vec3::new(4., 5., 6.), transforms)
+		});
+
+		assert_eq!(
+			OrbitTarget::point(Vec3::new(4., 4., 6.)).with_offset(Vec3::Y),
+			target
+		);
+	}
+
+	#[test]
+	fn move_orbit_target_entity_by_offset() {
+		let mut world = World::new();
+		let entity = world.spawn(GlobalTransform::from_xyz(1., 2., 3.)).id();
+		let mut target = OrbitTarget::entity(entity).with_offset(Vec3::Y);
+
+		with_transforms(&mut world, |transforms| {
+			target.move_to(Vec3::new(4., 5., 6.), transforms)
+		});
+
+		assert_eq!(
+			OrbitTarget::entity(entity).with_offset(Vec3::new(3., 3., 3.)),
+			target
+		);
+	}
+
+	#[test]
+	fn do_not_move_orbit_target_entity_without_position() {
+		let mut world = World::new();
+		let entity = world.spawn_empty().id();
+		let mut target = OrbitTarget::entity(entity).with_offset(Vec3::Y);
+
+		with_transforms(&mut world, |transforms| {
+			target.move_to(Vec3::new(4., 5., 6.), transforms)
+		});
+
+		assert_eq!(OrbitTarget::entity(entity).with_offset(Vec3::Y), target);
+	}
+
+	#[test]
+	fn focus_framing_cube() {
+		let bounds = Aabb::from_min_max(Vec3::new(0., 1., 2.), Vec3::new(2., 3., 4.));
+
+		let focus = Focus::framing(&bounds, PI / 2., 1., 1.);
+
+		assert_eq!(Vec3::new(1., 2., 3.), focus.anchor);
+		assert_approx_eq!(6_f32.sqrt(), focus.distance, 0.00001);
+	}
+
+	#[test]
+	fn focus_framing_with_padding() {
+		let bounds = Aabb::from_min_max(Vec3::splat(-1.), Vec3::splat(1.));
+
+		let focus = Focus::framing(&bounds, PI / 2., 1., 2.);
+
+		assert_approx_eq!(2. * 6_f32.sqrt(), focus.distance, 0.00001);
+	}
+
+	#[test]
+	fn focus_framing_by_horizontal_fov_for_narrow_viewports() {
+		let bounds = Aabb::from_min_max(Vec3::new(-3., -4., 0.), Vec3::new(3., 4., 0.));
+
+		let focus = Focus::framing(&bounds, PI / 2., 0.5, 1.);
+
+		assert_approx_eq!(5. * 5_f32.sqrt(), focus.distance, 0.00001);
+	}
+
+	fn transition(elapsed: f32) -> FocusTransition {
+		FocusTransition {
+			elapsed,
+			..FocusTransition::new(
+				Focus {
+					anchor: Vec3::ZERO,
+					distance: 10.,
+				},
+				Focus {
+					anchor: Vec3::new(2., 0., 0.),
+					distance: 20.,
+				},
+				2.,
+			)
+		}
+	}
+
+	#[test]
+	fn focus_transition_start() {
+		let transition = transition(0.);
+
+		assert_eq!(
+			(transition.from, false),
+			(transition.value(), transition.is_finished())
+		);
+	}
+
+	#[test]
+	fn focus_transition_eased() {
+		let quarter = transition(0.5).value();
+		let half = transition(1.).value();
+
+		assert_approx_eq!(
+			Vec3::new(0.3125, 0., 0.),
+			quarter.anchor,
+			Vec3::splat(0.00001)
+		);
+		assert_approx_eq!(11.5625, quarter.distance, 0.00001);
+		assert_approx_eq!(Vec3::new(1., 0., 0.), half.anchor, Vec3::splat(0.00001));
+		assert_approx_eq!(15., half.distance, 0.00001);
+	}
+
+	#[test]
+	fn focus_transition_finished() {
+		let transition = transition(3.);
+
+		assert_eq!(
+			(transition.to, true),
+			(transition.value(), transition.is_finished())
+		);
+	}
+
+	#[test]
+	fn focus_transition_without_duration_is_finished() {
+		let transition = FocusTransition::new(
+			Focus {
+				anchor: Vec3::ZERO,
+				distance: 10.,
+			},
+			Focus {
+				anchor: Vec3::ONE,
+				distance: 5.,
+			},
+			0.,
+		);
+
+		assert_eq!(
+			(transition.to, true),
+			(transition.value(), transition.is_finished())
+		);
+	}
+
+	fn bookmark_transition(elapsed: f32) -> BookmarkTransition {
+		BookmarkTransition {
+			elapsed,
+			..BookmarkTransition::new(
+				CameraBookmark::new(&Transform::from_xyz(0., 0., 2.), Vec3::ZERO),
+				CameraBookmark::new(
+					&Transform::from_xyz(5., 0., 0.).looking_at(Vec3::ZERO, Vec3::Y),
+					Vec3::X,
+				),
+				2.,
+			)
+		}
+	}
+
+	#[test]
+	fn bookmark_transition_eased() {
+		let transition = bookmark_transition(0.5);
+
+		assert_eq!(
+			(transition.from.lerp(&transition.to, 0.15625), false),
+			(transition.value(), transition.is_finished())
+		);
+	}
+
+	#[test]
+	fn bookmark_transition_finished() {
+		let transition = bookmark_transition(2.);
+
+		assert_eq!(
+			(transition.to, true),
+			(transition.value(), transition.is_finished())
+		);
+	}
+
+	#[test]
+	fn dissolve_transition_linear() {
+		let DissolveTransition(transition) = DissolveTransition::new(0.2, 1., 2.);
+		let transition = Transition {
+			elapsed: 0.5,
+			..transition
+		};
+
+		assert_eq!((0.4, false), (transition.value(), transition.is_finished()));
+	}
+
+	#[test]
+	fn dissolve_transition_finished() {
+		let (DissolveTransition(transition), _) = DissolveTransition::disappear(2.);
+		let transition = Transition {
+			elapsed: 3.,
+			..transition
+		};
+
+		assert_eq!((1., true), (transition.value(), transition.is_finished()));
+	}
+
+	#[test]
+	fn rules_pick_first_matching_rule() {
+		let rim = Handle::<CustomMaterial>::weak_from_u128(1);
+		let face = Handle::<CustomMaterial>::weak_from_u128(2);
+		let rules = ReplacementRules(vec![
+			ReplacementRule::material_name("Face", face.clone()),
+			ReplacementRule::material_name("Rim*", rim.clone()),
+			ReplacementRule::material_name("*", face.clone()),
+		]);
+
+		let replacement = rules.replacement_for(&ReplacementNames {
+			material: Some("RimMaterial"),
+			..default()
+		});
+
+		assert_eq!(Some(&rim), replacement);
+	}
+
+	#[test]
+	fn rules_match_mesh_name() {
+		let rim = Handle::<CustomMaterial>::weak_from_u128(1);
+		let rules = ReplacementRules(vec![ReplacementRule::mesh_name("Rim", rim.clone())]);
+
+		let replacement = rules.replacement_for(&ReplacementNames {
+			material: Some("Face"),
+			mesh: Some("Rim"),
+			nodes: vec!["Face"],
+		});
+
+		assert_eq!(Some(&rim), replacement);
+	}
+
+	#[test]
+	fn rules_match_any_node_name() {
+		let rim = Handle::<CustomMaterial>::weak_from_u128(1);
+		let rules = ReplacementRules(vec![ReplacementRule::node_name("Rim", rim.clone())]);
+
+		let replacement = rules.replacement_for(&ReplacementNames {
+			material: Some("Face"),
+			mesh: Some("Face"),
+			nodes: vec!["Shield", "Rim"],
+		});
+
+		assert_eq!(Some(&rim), replacement);
+	}
+
+	#[test]
+	fn rules_match_nothing() {
+		let rim = Handle::<CustomMaterial>::weak_from_u128(1);
+		let rules = ReplacementRules(vec![ReplacementRule::material_name("Rim", rim.clone())]);
+
+		let replacement = rules.replacement_for(&ReplacementNames {
+			material: None,
+			mesh: Some("Rim"),
+			nodes: vec!["Rim"],
+		});
+
+		assert_eq!(None, replacement);
+	}
+
+	#[test]
+	fn bookmark_distance_to_anchor() {
+		let bookmark = CameraBookmark::new(&Transform::from_xyz(1., 2., 2.), Vec3::new(1., 0., 0.));
+
+		assert_eq!(8_f32.sqrt(), bookmark.distance);
+	}
+
+	#[test]
+	fn bookmark_lerp_along_orbit() {
+		let from = CameraBookmark::new(
+			&Transform::from_xyz(0., 0., 2.).looking_at(Vec3::ZERO, Vec3::Y),
+			Vec3::ZERO,
+		);
+		let to = CameraBookmark::new(
+			&Transform::from_xyz(4., 0., 0.).looking_at(Vec3::ZERO, Vec3::Y),
+			Vec3::ZERO,
+		);
+
+		let half = from.lerp(&to, 0.5);
+
+		let direction = Quat::from_rotation_y(PI / 4.) * Vec3::Z;
+		assert_approx_eq!(direction * 3., half.translation, Vec3::splat(0.00001));
+		assert_approx_eq!(3., half.distance, 0.00001);
+	}
+
+	#[test]
+	fn bookmark_lerp_ends() {
+		let from = CameraBookmark::new(
+			&Transform::from_xyz(0., 1., 2.).looking_at(Vec3::ZERO, Vec3::Y),
+			Vec3::ZERO,
+		);
+		let to = CameraBookmark::new(
+			&Transform::from_xyz(4., 3., 0.).looking_at(Vec3::X, Vec3::Y),
+			Vec3::X,
+		);
+
+		let start = from.lerp(&to, 0.);
+		let end = from.lerp(&to, 1.);
+
+		assert_approx_eq!(from.translation, start.translation, Vec3::splat(0.00001));
+		assert_approx_eq!(to.translation, end.translation, Vec3::splat(0.00001));
+	}
+}
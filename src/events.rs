@@ -0,0 +1,36 @@
+use bevy::prelude::*;
+
+/// Pans the camera and its orbit target in the view plane.
+#[derive(Event, Debug, PartialEq, Clone, Copy)]
+pub struct PanMotion {
+	/// Screen space movement, like [`MouseMotion::delta`](bevy::input::mouse::MouseMotion)
+	pub delta: Vec2,
+}
+
+/// Orbits the camera around its orbit target.
+#[derive(Event, Debug, PartialEq, Clone, Copy)]
+pub struct OrbitMotion {
+	/// Screen space movement, like [`MouseMotion::delta`](bevy::input::mouse::MouseMotion)
+	pub delta: Vec2,
+}
+
+/// Zooms the camera towards its orbit target.
+#[derive(Event, Debug, PartialEq, Clone, Copy)]
+pub struct ZoomMotion {
+	/// Scrolled lines, like [`MouseWheel::y`](bevy::input::mouse::MouseWheel) in
+	/// [`MouseScrollUnit::Line`](bevy::input::mouse::MouseScrollUnit) units
+	pub lines: f32,
+}
+
+/// Turns the camera around the up axis of its orbit target.
+#[derive(Event, Debug, PartialEq, Clone, Copy)]
+pub struct TwistMotion {
+	/// Twisted angle in radians, clockwise positive, like
+	/// [`RotationGesture`](bevy::input::gestures::RotationGesture)
+	pub angle: f32,
+}
+
+/// Moves the orbit target of cameras to the center of the entity's meshes and zooms so
+/// they fill the viewport.
+#[derive(Event, Debug, PartialEq, Clone, Copy)]
+pub struct FocusOn(pub Entity);
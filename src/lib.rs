@@ -1,6 +1,8 @@
 pub mod bundles;
 pub mod components;
+pub mod events;
 pub mod material;
+pub mod plugins;
 pub mod resources;
 pub mod systems;
 pub mod tools;
@@ -1,36 +1,18 @@
 use bevy::{
 	color::palettes::css::{DARK_CYAN, WHITE},
-	input::mouse::{MouseMotion, MouseWheel},
 	prelude::*,
 };
 use project_zyheeda_bevy_shaders::{
 	bundles::MaterialAssetBundle,
-	components::ReplacementMaterial,
+	components::{OrbitInertia, OrbitTarget, ReplacementMaterial},
 	material::CustomMaterial,
-	resources::{CameraRotationSettings, CameraZoomSettings},
-	systems::{
-		cam_movement::cam_movement,
-		holding_button::holding_button,
-		replace_standard_material::replace_standard_material,
-		set_material_time::set_material_time,
-	},
+	plugins::ShaderPlaygroundPlugin,
 };
 
 fn main() {
 	App::new()
-		.add_plugins((DefaultPlugins, MaterialPlugin::<CustomMaterial>::default()))
-		.init_resource::<CameraRotationSettings>()
-		.init_resource::<CameraZoomSettings>()
+		.add_plugins((DefaultPlugins, ShaderPlaygroundPlugin::default()))
 		.add_systems(Startup, setup)
-		.add_systems(
-			Update,
-			(
-				cam_movement::<MouseMotion>.run_if(holding_button(MouseButton::Right)),
-				cam_movement::<MouseWheel>,
-			),
-		)
-		.add_systems(Update, replace_standard_material)
-		.add_systems(Update, set_material_time)
 		.run();
 }
 
@@ -56,6 +38,7 @@ fn setup(
 		color: DARK_CYAN.into(),
 		alpha_mode: AlphaMode::Blend,
 		color_texture: Some(asset_server.load("textures/grid.png")),
+		lit: true,
 		..default()
 	});
 
@@ -73,10 +56,14 @@ fn setup(
 		..default()
 	});
 
-	commands.spawn(Camera3dBundle {
-		transform: Transform::from_xyz(-2.0, 2.5, 5.0).looking_at(rotation_center, Vec3::Y),
-		..default()
-	});
+	commands.spawn((
+		Camera3dBundle {
+			transform: Transform::from_xyz(-2.0, 2.5, 5.0).looking_at(rotation_center, Vec3::Y),
+			..default()
+		},
+		OrbitTarget::point(rotation_center),
+		OrbitInertia::new(4.),
+	));
 
 	commands.spawn(PointLightBundle {
 		transform: Transform::from_xyz(5., 5., 5.),
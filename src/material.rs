@@ -1,18 +1,180 @@
+pub mod custom_material_uniform;
+pub mod dissolve;
+pub mod dissolve_material;
+pub mod hologram;
+pub mod material_time;
+pub mod shader_time_buffer;
+
 use bevy::{
+	pbr::{MaterialPipeline, MaterialPipelineKey},
 	prelude::*,
-	render::render_resource::{AsBindGroup, ShaderRef},
+	render::{
+		mesh::MeshVertexBufferLayoutRef,
+		render_asset::RenderAssets,
+		render_resource::{
+			AsBindGroup,
+			AsBindGroupError,
+			BindGroupLayout,
+			BindGroupLayoutEntry,
+			Face,
+			RenderPipelineDescriptor,
+			ShaderDefVal,
+			ShaderRef,
+			SpecializedMeshPipelineError,
+			UnpreparedBindGroup,
+		},
+		renderer::RenderDevice,
+		texture::{FallbackImage, GpuImage},
+	},
 };
+use custom_material_uniform::CustomMaterialUniform;
+use hologram::Hologram;
+use material_time::MaterialTime;
+use shader_time_buffer::{shader_time_binding, shader_time_layout_entry};
 
-#[derive(Asset, TypePath, AsBindGroup, Clone, Default)]
+/// Bound with the [`ShaderTime`](crate::resources::ShaderTime) uniform, which is written
+/// by the [`ShaderPlaygroundPlugin`](crate::plugins::ShaderPlaygroundPlugin).
+///
+/// The uniform buffer is shared by all render apps of the process. Registering only the
+/// [`MaterialPlugin`] of this material binds a zero time and logs a warning once.
+#[derive(Asset, TypePath, Debug, PartialEq, Clone, Default)]
 pub struct CustomMaterial {
-	#[uniform(0)]
 	pub color: LinearRgba,
-	#[uniform(1)]
-	pub time_secs: f32,
-	#[texture(2)]
-	#[sampler(3)]
+	pub time: MaterialTime,
 	pub color_texture: Option<Handle<Image>>,
+	pub emissive: LinearRgba,
+	pub normal_map_texture: Option<Handle<Image>>,
 	pub alpha_mode: AlphaMode,
+	/// Shades the material with bevy's PBR lighting instead of rendering
+	/// `color * color_texture` as is.
+	pub lit: bool,
+	pub hologram: Hologram,
+	/// Renders the material as a [`Hologram`].
+	pub hologram_enabled: bool,
+	/// Tints the color by the mesh's vertex colors, if it has any.
+	pub vertex_colors: bool,
+	/// Renders back faces, which are culled otherwise.
+	pub double_sided: bool,
+}
+
+/// The bindings of a [`CustomMaterial`] besides the shared [`ShaderTime`](crate::resources::ShaderTime).
+#[derive(AsBindGroup)]
+#[bind_group_data(CustomMaterialKey)]
+struct CustomMaterialBindings {
+	#[uniform(0)]
+	uniform: CustomMaterialUniform,
+	#[texture(1)]
+	#[sampler(2)]
+	color_texture: Option<Handle<Image>>,
+	#[texture(3)]
+	#[sampler(4)]
+	normal_map_texture: Option<Handle<Image>>,
+	key: CustomMaterialKey,
+}
+
+impl From<&CustomMaterial> for CustomMaterialBindings {
+	fn from(material: &CustomMaterial) -> Self {
+		Self {
+			uniform: CustomMaterialUniform {
+				color: material.color,
+				emissive: material.emissive,
+				hologram: material.hologram,
+				time: material.time,
+			},
+			color_texture: material.color_texture.clone(),
+			normal_map_texture: material.normal_map_texture.clone(),
+			key: CustomMaterialKey::from(material),
+		}
+	}
+}
+
+impl From<&CustomMaterialBindings> for CustomMaterialKey {
+	fn from(bindings: &CustomMaterialBindings) -> Self {
+		bindings.key
+	}
+}
+
+impl AsBindGroup for CustomMaterial {
+	type Data = CustomMaterialKey;
+
+	fn label() -> Option<&'static str> {
+		Some("custom_material")
+	}
+
+	fn unprepared_bind_group(
+		&self,
+		layout: &BindGroupLayout,
+		render_device: &RenderDevice,
+		images: &RenderAssets<GpuImage>,
+		fallback_image: &FallbackImage,
+	) -> Result<UnpreparedBindGroup<Self::Data>, AsBindGroupError> {
+		let mut bind_group = CustomMaterialBindings::from(self).unprepared_bind_group(
+			layout,
+			render_device,
+			images,
+			fallback_image,
+		)?;
+		bind_group.bindings.push(shader_time_binding(render_device));
+
+		Ok(bind_group)
+	}
+
+	fn bind_group_layout_entries(render_device: &RenderDevice) -> Vec<BindGroupLayoutEntry> {
+		let mut entries = CustomMaterialBindings::bind_group_layout_entries(render_device);
+		entries.push(shader_time_layout_entry());
+
+		entries
+	}
+}
+
+/// The parts of a [`CustomMaterial`] that require a specialized pipeline. Features that
+/// are not used are compiled out of the shader.
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy, Default)]
+pub struct CustomMaterialKey {
+	pub lit: bool,
+	pub hologram: bool,
+	pub color_texture: bool,
+	pub normal_map: bool,
+	pub vertex_colors: bool,
+	pub double_sided: bool,
+}
+
+impl CustomMaterialKey {
+	pub fn shader_defs(&self) -> Vec<ShaderDefVal> {
+		[
+			(self.lit, "LIT"),
+			(self.hologram, "HOLOGRAM"),
+			(self.color_texture, "COLOR_TEXTURE"),
+			(self.normal_map, "NORMAL_MAP"),
+			// `VERTEX_COLORS` is set by bevy when the mesh has vertex colors
+			(self.vertex_colors, "MATERIAL_VERTEX_COLORS"),
+			(self.double_sided, "DOUBLE_SIDED"),
+		]
+		.into_iter()
+		.filter(|(enabled, _)| *enabled)
+		.map(|(_, shader_def)| shader_def.into())
+		.collect()
+	}
+
+	pub fn cull_mode(&self) -> Option<Face> {
+		match self.double_sided {
+			true => None,
+			false => Some(Face::Back),
+		}
+	}
+}
+
+impl From<&CustomMaterial> for CustomMaterialKey {
+	fn from(material: &CustomMaterial) -> Self {
+		Self {
+			lit: material.lit,
+			hologram: material.hologram_enabled,
+			color_texture: material.color_texture.is_some(),
+			normal_map: material.normal_map_texture.is_some(),
+			vertex_colors: material.vertex_colors,
+			double_sided: material.double_sided,
+		}
+	}
 }
 
 impl Material for CustomMaterial {
@@ -27,4 +189,165 @@ impl Material for CustomMaterial {
 	fn alpha_mode(&self) -> AlphaMode {
 		self.alpha_mode
 	}
+
+	fn specialize(
+		_: &MaterialPipeline<Self>,
+		descriptor: &mut RenderPipelineDescriptor,
+		_: &MeshVertexBufferLayoutRef,
+		key: MaterialPipelineKey<Self>,
+	) -> Result<(), SpecializedMeshPipelineError> {
+		descriptor.primitive.cull_mode = key.bind_group_data.cull_mode();
+		if let Some(fragment) = descriptor.fragment.as_mut() {
+			fragment
+				.shader_defs
+				.extend(key.bind_group_data.shader_defs());
+		}
+
+		Ok(())
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	fn image(id: u128) -> Handle<Image> {
+		Handle::weak_from_u128(id)
+	}
+
+	#[test]
+	fn key_without_features_by_default() {
+		let material = CustomMaterial::default();
+
+		assert_eq!(
+			CustomMaterialKey::default(),
+			CustomMaterialKey::from(&material)
+		);
+	}
+
+	#[test]
+	fn key_lit() {
+		let material = CustomMaterial {
+			lit: true,
+			..default()
+		};
+
+		assert_eq!(
+			CustomMaterialKey {
+				lit: true,
+				..default()
+			},
+			CustomMaterialKey::from(&material)
+		);
+	}
+
+	#[test]
+	fn key_hologram() {
+		let material = CustomMaterial {
+			hologram_enabled: true,
+			..default()
+		};
+
+		assert_eq!(
+			CustomMaterialKey {
+				hologram: true,
+				..default()
+			},
+			CustomMaterialKey::from(&material)
+		);
+	}
+
+	#[test]
+	fn key_textures() {
+		let material = CustomMaterial {
+			color_texture: Some(image(1)),
+			normal_map_texture: Some(image(2)),
+			..default()
+		};
+
+		assert_eq!(
+			CustomMaterialKey {
+				color_texture: true,
+				normal_map: true,
+				..default()
+			},
+			CustomMaterialKey::from(&material)
+		);
+	}
+
+	#[test]
+	fn key_vertex_colors_and_double_sided() {
+		let material = CustomMaterial {
+			vertex_colors: true,
+			double_sided: true,
+			..default()
+		};
+
+		assert_eq!(
+			CustomMaterialKey {
+				vertex_colors: true,
+				double_sided: true,
+				..default()
+			},
+			CustomMaterialKey::from(&material)
+		);
+	}
+
+	#[test]
+	fn no_shader_defs_by_default() {
+		let key = CustomMaterialKey::default();
+
+		assert_eq!(vec![] as Vec<ShaderDefVal>, key.shader_defs());
+	}
+
+	#[test]
+	fn lit_shader_def() {
+		let key = CustomMaterialKey {
+			lit: true,
+			..default()
+		};
+
+		assert_eq!(vec![ShaderDefVal::from("LIT")], key.shader_defs());
+	}
+
+	#[test]
+	fn all_shader_defs() {
+		let key = CustomMaterialKey {
+			lit: true,
+			hologram: true,
+			color_texture: true,
+			normal_map: true,
+			vertex_colors: true,
+			double_sided: true,
+		};
+
+		assert_eq!(
+			vec![
+				ShaderDefVal::from("LIT"),
+				ShaderDefVal::from("HOLOGRAM"),
+				ShaderDefVal::from("COLOR_TEXTURE"),
+				ShaderDefVal::from("NORMAL_MAP"),
+				ShaderDefVal::from("MATERIAL_VERTEX_COLORS"),
+				ShaderDefVal::from("DOUBLE_SIDED"),
+			],
+			key.shader_defs()
+		);
+	}
+
+	#[test]
+	fn cull_back_faces() {
+		let key = CustomMaterialKey::default();
+
+		assert_eq!(Some(Face::Back), key.cull_mode());
+	}
+
+	#[test]
+	fn do_not_cull_when_double_sided() {
+		let key = CustomMaterialKey {
+			double_sided: true,
+			..default()
+		};
+
+		assert_eq!(None, key.cull_mode());
+	}
 }
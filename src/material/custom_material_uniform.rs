@@ -0,0 +1,84 @@
+use super::{hologram::Hologram, material_time::MaterialTime};
+use crate::tools::shader_type;
+use bevy::color::LinearRgba;
+
+shader_type! {
+	/// All uniform parameters of a [`CustomMaterial`](crate::material::CustomMaterial),
+	/// bound as one struct. Must match `CustomMaterial` in `custom_material.wgsl`.
+	#[derive(Debug, PartialEq, Clone, Copy)]
+	pub struct CustomMaterialUniform {
+		pub color: LinearRgba,
+		pub emissive: LinearRgba,
+		pub hologram: Hologram,
+		pub time: MaterialTime,
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use bevy::render::render_resource::{encase::UniformBuffer, ShaderType};
+
+	fn f32_at(bytes: &[u8], offset: usize) -> f32 {
+		f32::from_le_bytes(bytes[offset..offset + 4].try_into().unwrap())
+	}
+
+	#[test]
+	fn std140_size() {
+		assert_eq!(80, CustomMaterialUniform::min_size().get());
+	}
+
+	#[test]
+	fn std140_offsets() {
+		let uniform = CustomMaterialUniform {
+			color: LinearRgba::new(1., 2., 3., 4.),
+			emissive: LinearRgba::new(5., 6., 7., 8.),
+			hologram: Hologram {
+				rim_color: LinearRgba::new(9., 10., 11., 12.),
+				rim_power: 13.,
+				scanline_density: 14.,
+				scroll_speed: 15.,
+				flicker: 16.,
+			},
+			time: MaterialTime {
+				scale: 17.,
+				offset: 18.,
+			},
+		};
+		let mut buffer = UniformBuffer::new(vec![]);
+		buffer.write(&uniform).unwrap();
+		let bytes = buffer.into_inner();
+
+		// offsets of the WGSL struct: vec4 fields are 16 byte aligned, the rest 4 byte
+		let expected = [
+			(0, 1.),
+			(4, 2.),
+			(8, 3.),
+			(12, 4.),
+			(16, 5.),
+			(20, 6.),
+			(24, 7.),
+			(28, 8.),
+			(32, 9.),
+			(36, 10.),
+			(40, 11.),
+			(44, 12.),
+			(48, 13.),
+			(52, 14.),
+			(56, 15.),
+			(60, 16.),
+			(64, 17.),
+			(68, 18.),
+		];
+		assert_eq!(
+			(80, expected.to_vec()),
+			(
+				bytes.len(),
+				expected
+					.iter()
+					.map(|(offset, _)| (*offset, f32_at(&bytes, *offset)))
+					.collect::<Vec<_>>()
+			)
+		);
+	}
+}
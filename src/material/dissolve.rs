@@ -0,0 +1,24 @@
+use crate::tools::shader_type;
+use bevy::color::LinearRgba;
+
+shader_type! {
+	/// Dissolve parameters of a [`DissolveMaterial`](crate::material::dissolve_material::DissolveMaterial).
+	#[derive(Debug, PartialEq, Clone, Copy)]
+	pub struct Dissolve {
+		/// From 0 (solid) to 1 (fully dissolved)
+		pub threshold: f32,
+		/// Noise range above the threshold that glows in the `edge_color`
+		pub edge_width: f32,
+		pub edge_color: LinearRgba,
+	}
+}
+
+impl Default for Dissolve {
+	fn default() -> Self {
+		Self {
+			threshold: 0.,
+			edge_width: 0.05,
+			edge_color: LinearRgba::rgb(4., 1.5, 0.3),
+		}
+	}
+}
@@ -0,0 +1,66 @@
+use super::dissolve::Dissolve;
+use bevy::{
+	prelude::*,
+	render::render_resource::{AsBindGroup, ShaderRef},
+};
+
+/// Renders `color * color_texture` and discards fragments whose noise value lies below
+/// the [`Dissolve::threshold`], glowing along the dissolving edge.
+///
+/// Fragments with an alpha below the `alpha_cutoff` are discarded, as with
+/// [`AlphaMode::Mask`].
+///
+/// Animate the threshold with a [`DissolveTransition`](crate::components::DissolveTransition).
+#[derive(Asset, TypePath, AsBindGroup, Clone)]
+pub struct DissolveMaterial {
+	#[uniform(0)]
+	pub color: LinearRgba,
+	#[texture(1)]
+	#[sampler(2)]
+	pub color_texture: Option<Handle<Image>>,
+	/// Grayscale noise, read from the red channel
+	#[texture(3)]
+	#[sampler(4)]
+	pub noise_texture: Option<Handle<Image>>,
+	#[uniform(5)]
+	pub dissolve: Dissolve,
+	#[uniform(6)]
+	pub alpha_cutoff: f32,
+}
+
+impl Default for DissolveMaterial {
+	fn default() -> Self {
+		Self {
+			color: LinearRgba::default(),
+			color_texture: None,
+			noise_texture: None,
+			dissolve: Dissolve::default(),
+			alpha_cutoff: 0.5,
+		}
+	}
+}
+
+impl Material for DissolveMaterial {
+	fn fragment_shader() -> ShaderRef {
+		"shaders/dissolve_material.wgsl".into()
+	}
+
+	fn alpha_mode(&self) -> AlphaMode {
+		AlphaMode::Mask(self.alpha_cutoff)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn mask_alpha_by_alpha_cutoff() {
+		let material = DissolveMaterial {
+			alpha_cutoff: 0.3,
+			..default()
+		};
+
+		assert_eq!(AlphaMode::Mask(0.3), material.alpha_mode());
+	}
+}
@@ -0,0 +1,31 @@
+use crate::tools::shader_type;
+use bevy::color::LinearRgba;
+
+shader_type! {
+	/// Parameters of the hologram effect: a view dependent rim glow and scanlines
+	/// scrolling upwards in world space, both flickering over time.
+	#[derive(Debug, PartialEq, Clone, Copy)]
+	pub struct Hologram {
+		pub rim_color: LinearRgba,
+		/// Higher values narrow the rim glow towards the silhouette.
+		pub rim_power: f32,
+		/// Scanlines per world unit.
+		pub scanline_density: f32,
+		/// World units per second the scanlines move upwards.
+		pub scroll_speed: f32,
+		/// How much the brightness flickers, from 0 (steady) to 1.
+		pub flicker: f32,
+	}
+}
+
+impl Default for Hologram {
+	fn default() -> Self {
+		Self {
+			rim_color: LinearRgba::rgb(0.3, 0.8, 1.),
+			rim_power: 2.,
+			scanline_density: 20.,
+			scroll_speed: 0.5,
+			flicker: 0.1,
+		}
+	}
+}
@@ -0,0 +1,20 @@
+use crate::tools::shader_type;
+
+shader_type! {
+	/// Maps the global shader time to the time of a material:
+	/// `elapsed_secs * scale + offset`.
+	#[derive(Debug, PartialEq, Clone, Copy)]
+	pub struct MaterialTime {
+		pub scale: f32,
+		pub offset: f32,
+	}
+}
+
+impl Default for MaterialTime {
+	fn default() -> Self {
+		Self {
+			scale: 1.,
+			offset: 0.,
+		}
+	}
+}
@@ -0,0 +1,148 @@
+use crate::resources::ShaderTime;
+use bevy::{
+	log::warn_once,
+	render::{
+		render_resource::{
+			encase::UniformBuffer,
+			BindGroupLayoutEntry,
+			BindingType,
+			Buffer,
+			BufferBindingType,
+			BufferInitDescriptor,
+			BufferUsages,
+			OwnedBindingResource,
+			ShaderStages,
+			ShaderType,
+		},
+		renderer::{RenderDevice, RenderQueue},
+	},
+};
+use std::sync::RwLock;
+
+/// Binding of the [`ShaderTime`] uniform in the bind groups of crate materials. Must
+/// match `shader_time_uniform` in `shader_time.wgsl`.
+pub const SHADER_TIME_BINDING: u32 = 100;
+
+/// The uniform buffer holding the extracted [`ShaderTime`], shared by the bind groups of
+/// all crate materials and rewritten every frame.
+///
+/// [`AsBindGroup`](bevy::render::render_resource::AsBindGroup) has no access to render
+/// world resources, so the buffer is kept here and shared by all render apps of the
+/// process.
+static SHADER_TIME_BUFFER: RwLock<Option<Buffer>> = RwLock::new(None);
+
+pub fn shader_time_layout_entry() -> BindGroupLayoutEntry {
+	BindGroupLayoutEntry {
+		binding: SHADER_TIME_BINDING,
+		visibility: ShaderStages::VERTEX_FRAGMENT,
+		ty: BindingType::Buffer {
+			ty: BufferBindingType::Uniform,
+			has_dynamic_offset: false,
+			min_binding_size: Some(ShaderTime::min_size()),
+		},
+		count: None,
+	}
+}
+
+/// Binds the shared [`ShaderTime`] buffer.
+///
+/// When nothing wrote the buffer yet, a zero [`ShaderTime`] is bound and a warning is
+/// logged once, so materials still render, just without animation.
+pub fn shader_time_binding(render_device: &RenderDevice) -> (u32, OwnedBindingResource) {
+	let mut buffer = SHADER_TIME_BUFFER.write().unwrap();
+	let buffer = buffer.get_or_insert_with(|| {
+		warn_once!(
+			"No ShaderTime was written before binding crate materials, their time stays \
+			 zero. Add the ShaderPlaygroundPlugin to write it every frame."
+		);
+		create_buffer(&ShaderTime::default(), render_device)
+	});
+
+	(
+		SHADER_TIME_BINDING,
+		OwnedBindingResource::Buffer(buffer.clone()),
+	)
+}
+
+/// Writes the `shader_time` to the shared buffer, creating it on first use.
+pub fn write_shader_time(
+	shader_time: &ShaderTime,
+	render_device: &RenderDevice,
+	render_queue: &RenderQueue,
+) {
+	let mut buffer = SHADER_TIME_BUFFER.write().unwrap();
+
+	match buffer.as_ref() {
+		Some(buffer) => render_queue.write_buffer(buffer, 0, &shader_time_bytes(shader_time)),
+		None => *buffer = Some(create_buffer(shader_time, render_device)),
+	}
+}
+
+fn create_buffer(shader_time: &ShaderTime, render_device: &RenderDevice) -> Buffer {
+	render_device.create_buffer_with_data(&BufferInitDescriptor {
+		label: Some("shader_time_buffer"),
+		contents: &shader_time_bytes(shader_time),
+		usage: BufferUsages::UNIFORM | BufferUsages::COPY_DST,
+	})
+}
+
+fn shader_time_bytes(shader_time: &ShaderTime) -> Vec<u8> {
+	let mut buffer = UniformBuffer::new(vec![]);
+	buffer.write(shader_time).unwrap();
+	buffer.into_inner()
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	fn f32_at(bytes: &[u8], offset: usize) -> f32 {
+		f32::from_le_bytes(bytes[offset..offset + 4].try_into().unwrap())
+	}
+
+	fn u32_at(bytes: &[u8], offset: usize) -> u32 {
+		u32::from_le_bytes(bytes[offset..offset + 4].try_into().unwrap())
+	}
+
+	#[test]
+	fn std140_offsets() {
+		let bytes = shader_time_bytes(&ShaderTime {
+			elapsed_secs: 1.,
+			delta_secs: 2.,
+			frame_count: 3,
+			sin_time: 4.,
+			cos_time: 5.,
+		});
+
+		// offsets of the WGSL struct, all fields are 4 byte aligned
+		assert_eq!(
+			(20, 1., 2., 3, 4., 5.),
+			(
+				bytes.len(),
+				f32_at(&bytes, 0),
+				f32_at(&bytes, 4),
+				u32_at(&bytes, 8),
+				f32_at(&bytes, 12),
+				f32_at(&bytes, 16),
+			)
+		);
+	}
+
+	#[test]
+	fn layout_entry_covers_shader_time() {
+		let entry = shader_time_layout_entry();
+
+		assert_eq!(
+			(
+				SHADER_TIME_BINDING,
+				ShaderStages::VERTEX_FRAGMENT,
+				BindingType::Buffer {
+					ty: BufferBindingType::Uniform,
+					has_dynamic_offset: false,
+					min_binding_size: Some(ShaderTime::min_size()),
+				}
+			),
+			(entry.binding, entry.visibility, entry.ty)
+		);
+	}
+}
@@ -0,0 +1,855 @@
+use crate::{
+	events::{FocusOn, OrbitMotion, PanMotion, TwistMotion, ZoomMotion},
+	material::{dissolve_material::DissolveMaterial, CustomMaterial},
+	resources::{
+		CameraBookmarkSettings,
+		CameraBookmarks,
+		CameraFocusSettings,
+		CameraInputBindings,
+		CameraPanSettings,
+		CameraProjectionSettings,
+		CameraRotationSettings,
+		CameraZoomSettings,
+		InheritedMaterials,
+		ShaderTime,
+	},
+	systems::{
+		animate_bookmark::animate_bookmark,
+		animate_dissolve::animate_dissolve,
+		animate_focus::animate_focus,
+		apply_orbit_inertia::apply_orbit_inertia,
+		bookmark_hotkeys::bookmark_hotkeys,
+		cam_movement::cam_movement,
+		feed_orbit_inertia::feed_orbit_inertia,
+		feed_orbit_motion_inertia::feed_orbit_motion_inertia,
+		focus_on::focus_on,
+		follow_orbit_target::follow_orbit_target,
+		holding_button::{holding_any, holding_button},
+		load_camera_bookmarks::load_camera_bookmarks,
+		override_materials::override_materials,
+		refresh_inherited_materials::refresh_inherited_materials,
+		replace_material::replace_material,
+		restore_material::restore_material,
+		send_camera_input::send_camera_input,
+		send_pan_motion::send_pan_motion,
+		send_touch_motion::send_touch_motion,
+		smooth_zoom::smooth_zoom,
+		stop_orbit_inertia::stop_orbit_inertia,
+		sync_orthographic_scale::sync_orthographic_scale,
+		toggle_projection::toggle_projection,
+		update_shader_time::update_shader_time,
+		write_shader_time_buffer::write_shader_time_buffer,
+	},
+};
+use bevy::{
+	ecs::{intern::Interned, schedule::ScheduleLabel},
+	input::{
+		gestures::{PinchGesture, RotationGesture},
+		mouse::{MouseMotion, MouseWheel},
+	},
+	pbr::PreparedMaterial,
+	prelude::*,
+	render::{
+		extract_resource::ExtractResourcePlugin,
+		render_asset::prepare_assets,
+		Render,
+		RenderApp,
+		RenderSet,
+	},
+};
+use std::marker::PhantomData;
+
+/// Where the systems of a [`ShaderPlaygroundPlugin`] feature are scheduled.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Placement {
+	schedule: Interned<dyn ScheduleLabel>,
+	set: Option<Interned<dyn SystemSet>>,
+}
+
+impl Placement {
+	pub fn new(schedule: impl ScheduleLabel) -> Self {
+		Self {
+			schedule: schedule.intern(),
+			set: None,
+		}
+	}
+
+	pub fn in_set(self, set: impl SystemSet) -> Self {
+		Self {
+			set: Some(set.intern()),
+			..self
+		}
+	}
+
+	fn add_systems<TMarker>(&self, app: &mut App, systems: impl IntoSystemConfigs<TMarker>) {
+		match self.set {
+			Some(set) => app.add_systems(self.schedule, systems.in_set(set)),
+			None => app.add_systems(self.schedule, systems),
+		};
+	}
+}
+
+impl Default for Placement {
+	fn default() -> Self {
+		Self::new(Update)
+	}
+}
+
+/// Registers [`CustomMaterial`] and the systems driving the shader playground.
+///
+/// Each feature is enabled in [`Update`] by default and can be disabled or moved
+/// to another schedule and system set independently.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ShaderPlaygroundPlugin {
+	camera_controls: Option<Placement>,
+	material_replacement: Option<Placement>,
+	material_time: Option<Placement>,
+	dissolve: Option<Placement>,
+}
+
+impl ShaderPlaygroundPlugin {
+	pub fn with_camera_controls(self, placement: Placement) -> Self {
+		Self {
+			camera_controls: Some(placement),
+			..self
+		}
+	}
+
+	pub fn without_camera_controls(self) -> Self {
+		Self {
+			camera_controls: None,
+			..self
+		}
+	}
+
+	pub fn with_material_replacement(self, placement: Placement) -> Self {
+		Self {
+			material_replacement: Some(placement),
+			..self
+		}
+	}
+
+	pub fn without_material_replacement(self) -> Self {
+		Self {
+			material_replacement: None,
+			..self
+		}
+	}
+
+	pub fn with_material_time(self, placement: Placement) -> Self {
+		Self {
+			material_time: Some(placement),
+			..self
+		}
+	}
+
+	pub fn without_material_time(self) -> Self {
+		Self {
+			material_time: None,
+			..self
+		}
+	}
+
+	pub fn with_dissolve(self, placement: Placement) -> Self {
+		Self {
+			dissolve: Some(placement),
+			..self
+		}
+	}
+
+	pub fn without_dissolve(self) -> Self {
+		Self {
+			dissolve: None,
+			..self
+		}
+	}
+}
+
+impl Default for ShaderPlaygroundPlugin {
+	fn default() -> Self {
+		Self {
+			camera_controls: Some(Placement::default()),
+			material_replacement: Some(Placement::default()),
+			material_time: Some(Placement::default()),
+			dissolve: Some(Placement::default()),
+		}
+	}
+}
+
+impl Plugin for ShaderPlaygroundPlugin {
+	fn build(&self, app: &mut App) {
+		if !app.is_plugin_added::<MaterialPlugin<CustomMaterial>>() {
+			app.add_plugins(MaterialPlugin::<CustomMaterial>::default());
+		}
+		if let Some(render_app) = app.get_sub_app_mut(RenderApp) {
+			render_app.add_systems(
+				Render,
+				write_shader_time_buffer
+					.in_set(RenderSet::PrepareAssets)
+					.before(prepare_assets::<PreparedMaterial<CustomMaterial>>),
+			);
+		}
+
+		if let Some(placement) = self.camera_controls {
+			app.init_resource::<CameraRotationSettings>()
+				.init_resource::<CameraZoomSettings>()
+				.init_resource::<CameraPanSettings>()
+				.init_resource::<CameraInputBindings>()
+				.init_resource::<CameraFocusSettings>()
+				.init_resource::<CameraBookmarkSettings>()
+				.init_resource::<CameraBookmarks>()
+				.init_resource::<CameraProjectionSettings>()
+				.add_event::<FocusOn>()
+				.add_event::<OrbitMotion>()
+				.add_event::<PanMotion>()
+				.add_event::<ZoomMotion>()
+				.add_event::<TwistMotion>();
+			placement.add_systems(
+				app,
+				(
+					follow_orbit_target,
+					(
+						send_camera_input,
+						send_pan_motion.run_if(panning()),
+						send_touch_motion,
+						toggle_projection,
+					),
+					(
+						cam_movement::<MouseMotion>.run_if(rotating()),
+						cam_movement::<MouseWheel>,
+						cam_movement::<PinchGesture>,
+						cam_movement::<RotationGesture>,
+						cam_movement::<OrbitMotion>,
+						cam_movement::<PanMotion>,
+						cam_movement::<ZoomMotion>,
+						cam_movement::<TwistMotion>,
+						smooth_zoom,
+						(
+							feed_orbit_inertia.run_if(rotating()),
+							feed_orbit_motion_inertia,
+							stop_orbit_inertia,
+							apply_orbit_inertia,
+						)
+							.chain(),
+					),
+					(focus_on, animate_focus).chain(),
+					(bookmark_hotkeys, animate_bookmark).chain(),
+					sync_orthographic_scale,
+				)
+					.chain(),
+			);
+			app.add_systems(Startup, load_camera_bookmarks);
+		}
+
+		if let Some(placement) = self.material_replacement {
+			app.init_resource::<InheritedMaterials<StandardMaterial, CustomMaterial>>()
+				.add_plugins(
+					MaterialReplacementPlugin::<StandardMaterial, CustomMaterial>::new(placement),
+				);
+		}
+
+		if let Some(placement) = self.material_time {
+			app.add_plugins(MaterialTimePlugin::<Real>::new(placement));
+		}
+
+		if let Some(placement) = self.dissolve {
+			if !app.is_plugin_added::<MaterialPlugin<DissolveMaterial>>() {
+				app.add_plugins(MaterialPlugin::<DissolveMaterial>::default());
+			}
+			placement.add_systems(app, animate_dissolve);
+		}
+	}
+}
+
+fn holding_shift() -> impl Condition<()> {
+	IntoSystem::into_system(holding_any([KeyCode::ShiftLeft, KeyCode::ShiftRight]))
+}
+
+fn rotating() -> impl Condition<()> {
+	holding_button(MouseButton::Right).and_then(not(holding_shift()))
+}
+
+fn panning() -> impl Condition<()> {
+	holding_button(MouseButton::Middle)
+		.or_else(holding_button(MouseButton::Right).and_then(holding_shift()))
+}
+
+/// Replaces `TSource` materials below a [`ReplacementMaterial<TTarget>`](crate::components::ReplacementMaterial)
+/// with `TTarget` materials and restores them when the replacement is removed or changed.
+///
+/// Add it once per material pair.
+pub struct MaterialReplacementPlugin<TSource, TTarget> {
+	placement: Placement,
+	phantom_data: PhantomData<fn() -> (TSource, TTarget)>,
+}
+
+impl<TSource, TTarget> MaterialReplacementPlugin<TSource, TTarget> {
+	pub fn new(placement: Placement) -> Self {
+		Self {
+			placement,
+			phantom_data: PhantomData,
+		}
+	}
+}
+
+impl<TSource, TTarget> Default for MaterialReplacementPlugin<TSource, TTarget> {
+	fn default() -> Self {
+		Self::new(Placement::default())
+	}
+}
+
+impl<TSource: Material, TTarget: Material> Plugin for MaterialReplacementPlugin<TSource, TTarget> {
+	fn build(&self, app: &mut App) {
+		self.placement.add_systems(
+			app,
+			(
+				refresh_inherited_materials::<TSource, TTarget>,
+				restore_material::<TSource, TTarget>,
+				replace_material::<TSource, TTarget>,
+			)
+				.chain(),
+		);
+	}
+}
+
+/// Animates [`CustomMaterial`]s with the time of the `TTime` clock, like [`Real`],
+/// [`Virtual`] to pause with the game, [`Fixed`] or a custom [`Time`] context.
+///
+/// The time is written to the [`ShaderTime`] resource and extracted into the uniform
+/// buffer the materials read via `shader_time()`. [`ShaderPlaygroundPlugin`] adds it with
+/// [`Real`] time, disable its material time to use another clock.
+pub struct MaterialTimePlugin<TTime> {
+	placement: Placement,
+	phantom_data: PhantomData<fn() -> TTime>,
+}
+
+impl<TTime> MaterialTimePlugin<TTime> {
+	pub fn new(placement: Placement) -> Self {
+		Self {
+			placement,
+			phantom_data: PhantomData,
+		}
+	}
+}
+
+impl<TTime> Default for MaterialTimePlugin<TTime> {
+	fn default() -> Self {
+		Self::new(Placement::default())
+	}
+}
+
+impl<TTime: Default + Send + Sync + 'static> Plugin for MaterialTimePlugin<TTime> {
+	fn build(&self, app: &mut App) {
+		app.init_resource::<ShaderTime>()
+			.add_plugins(ExtractResourcePlugin::<ShaderTime>::default());
+		self.placement.add_systems(
+			app,
+			(update_shader_time::<TTime>, override_materials).chain(),
+		);
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use bevy::{ecs::schedule::NodeId, input::InputPlugin, render::render_resource::Shader};
+
+	#[derive(SystemSet, Debug, Clone, PartialEq, Eq, Hash)]
+	struct MySet;
+
+	fn setup(plugin: ShaderPlaygroundPlugin) -> App {
+		let mut app = App::new();
+		app.add_plugins((MinimalPlugins, AssetPlugin::default(), InputPlugin));
+		app.init_asset::<Shader>();
+		app.add_plugins(plugin);
+
+		app
+	}
+
+	fn system_ids(app: &App, schedule: impl ScheduleLabel, name: &str) -> Vec<NodeId> {
+		let Some(schedule) = app.get_schedule(schedule) else {
+			return vec![];
+		};
+
+		schedule
+			.graph()
+			.systems()
+			.filter(|(_, system, _)| system.name().contains(name))
+			.map(|(id, ..)| id)
+			.collect()
+	}
+
+	fn is_in_set(
+		app: &App,
+		schedule: impl ScheduleLabel,
+		system: NodeId,
+		set: impl SystemSet,
+	) -> bool {
+		let graph = app.get_schedule(schedule).unwrap().graph();
+		let set = set.intern();
+
+		graph
+			.system_sets()
+			.filter(|(_, candidate, _)| **candidate == *set)
+			.any(|(id, ..)| graph.hierarchy().graph().contains_edge(id, system))
+	}
+
+	#[test]
+	fn register_custom_material() {
+		let app = setup(ShaderPlaygroundPlugin::default());
+
+		assert!(app.world().contains_resource::<Assets<CustomMaterial>>());
+	}
+
+	#[test]
+	fn register_camera_controls() {
+		let app = setup(ShaderPlaygroundPlugin::default());
+
+		assert_eq!(
+			(true, true, true, 1, 1, 1, 1),
+			(
+				app.world().contains_resource::<CameraRotationSettings>(),
+				app.world().contains_resource::<CameraZoomSettings>(),
+				app.world().contains_resource::<CameraPanSettings>(),
+				system_ids(&app, Update, "follow_orbit_target").len(),
+				system_ids(&app, Update, "send_pan_motion").len(),
+				system_ids(&app, Update, "cam_movement<bevy_input::mouse::MouseMotion>").len(),
+				system_ids(&app, Update, "cam_movement<bevy_input::mouse::MouseWheel>").len(),
+			)
+		);
+	}
+
+	#[test]
+	fn register_camera_input() {
+		let app = setup(ShaderPlaygroundPlugin::default());
+
+		assert_eq!(
+			(true, 1, 1, 1, 1),
+			(
+				app.world().contains_resource::<CameraInputBindings>(),
+				system_ids(&app, Update, "send_camera_input").len(),
+				system_ids(
+					&app,
+					Update,
+					"cam_movement<project_zyheeda_bevy_shaders::events::OrbitMotion>"
+				)
+				.len(),
+				system_ids(
+					&app,
+					Update,
+					"cam_movement<project_zyheeda_bevy_shaders::events::PanMotion>"
+				)
+				.len(),
+				system_ids(
+					&app,
+					Update,
+					"cam_movement<project_zyheeda_bevy_shaders::events::ZoomMotion>"
+				)
+				.len(),
+			)
+		);
+	}
+
+	#[test]
+	fn register_smooth_zoom() {
+		let app = setup(ShaderPlaygroundPlugin::default());
+
+		assert_eq!(1, system_ids(&app, Update, "smooth_zoom").len());
+	}
+
+	#[test]
+	fn register_orbit_inertia() {
+		let app = setup(ShaderPlaygroundPlugin::default());
+
+		assert_eq!(
+			(1, 1, 1, 1),
+			(
+				system_ids(&app, Update, "feed_orbit_inertia").len(),
+				system_ids(&app, Update, "feed_orbit_motion_inertia").len(),
+				system_ids(&app, Update, "stop_orbit_inertia").len(),
+				system_ids(&app, Update, "apply_orbit_inertia").len(),
+			)
+		);
+	}
+
+	#[test]
+	fn register_touch_controls() {
+		let app = setup(ShaderPlaygroundPlugin::default());
+
+		assert_eq!(
+			(1, 1, 1, 1),
+			(
+				system_ids(&app, Update, "send_touch_motion").len(),
+				system_ids(
+					&app,
+					Update,
+					"cam_movement<bevy_input::gestures::PinchGesture>"
+				)
+				.len(),
+				system_ids(
+					&app,
+					Update,
+					"cam_movement<bevy_input::gestures::RotationGesture>"
+				)
+				.len(),
+				system_ids(
+					&app,
+					Update,
+					"cam_movement<project_zyheeda_bevy_shaders::events::TwistMotion>"
+				)
+				.len(),
+			)
+		);
+	}
+
+	#[test]
+	fn register_camera_focus() {
+		let app = setup(ShaderPlaygroundPlugin::default());
+
+		assert_eq!(
+			(true, 1, 1),
+			(
+				app.world().contains_resource::<CameraFocusSettings>(),
+				system_ids(&app, Update, "focus_on").len(),
+				system_ids(&app, Update, "animate_focus").len(),
+			)
+		);
+	}
+
+	#[test]
+	fn register_camera_bookmarks() {
+		let app = setup(ShaderPlaygroundPlugin::default());
+
+		assert_eq!(
+			(true, true, 1, 1, 1),
+			(
+				app.world().contains_resource::<CameraBookmarkSettings>(),
+				app.world().contains_resource::<CameraBookmarks>(),
+				system_ids(&app, Update, "bookmark_hotkeys").len(),
+				system_ids(&app, Update, "animate_bookmark").len(),
+				system_ids(&app, Startup, "load_camera_bookmarks").len(),
+			)
+		);
+	}
+
+	#[test]
+	fn register_camera_projection() {
+		let app = setup(ShaderPlaygroundPlugin::default());
+
+		assert_eq!(
+			(true, 1, 1),
+			(
+				app.world().contains_resource::<CameraProjectionSettings>(),
+				system_ids(&app, Update, "toggle_projection").len(),
+				system_ids(&app, Update, "sync_orthographic_scale").len(),
+			)
+		);
+	}
+
+	#[test]
+	fn do_not_register_camera_controls_when_disabled() {
+		let app = setup(ShaderPlaygroundPlugin::default().without_camera_controls());
+
+		assert_eq!(
+			(
+				(false, false, false, false, false, false, false, false),
+				(0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0)
+			),
+			(
+				(
+					app.world().contains_resource::<CameraRotationSettings>(),
+					app.world().contains_resource::<CameraZoomSettings>(),
+					app.world().contains_resource::<CameraPanSettings>(),
+					app.world().contains_resource::<CameraInputBindings>(),
+					app.world().contains_resource::<CameraFocusSettings>(),
+					app.world().contains_resource::<CameraBookmarkSettings>(),
+					app.world().contains_resource::<CameraBookmarks>(),
+					app.world().contains_resource::<CameraProjectionSettings>(),
+				),
+				(
+					system_ids(&app, Update, "send_pan_motion").len(),
+					system_ids(&app, Update, "send_camera_input").len(),
+					system_ids(&app, Update, "follow_orbit_target").len(),
+					system_ids(&app, Update, "smooth_zoom").len(),
+					system_ids(&app, Update, "orbit_inertia").len(),
+					system_ids(&app, Update, "cam_movement").len(),
+					system_ids(&app, Update, "focus").len(),
+					system_ids(&app, Update, "bookmark").len(),
+					system_ids(&app, Startup, "bookmark").len(),
+					system_ids(&app, Update, "send_touch_motion").len(),
+					system_ids(&app, Update, "projection").len(),
+					system_ids(&app, Update, "orthographic").len(),
+				),
+			)
+		);
+	}
+
+	#[test]
+	fn register_material_replacement() {
+		let app = setup(ShaderPlaygroundPlugin::default());
+
+		assert_eq!(
+			(1, 1, 1),
+			(
+				system_ids(&app, Update, "refresh_inherited_materials").len(),
+				system_ids(&app, Update, "restore_material").len(),
+				system_ids(&app, Update, "replace_material").len(),
+			)
+		);
+	}
+
+	#[test]
+	fn do_not_register_material_replacement_when_disabled() {
+		let app = setup(ShaderPlaygroundPlugin::default().without_material_replacement());
+
+		assert_eq!(
+			(0, 0, 0),
+			(
+				system_ids(&app, Update, "refresh_inherited_materials").len(),
+				system_ids(&app, Update, "restore_material").len(),
+				system_ids(&app, Update, "replace_material").len(),
+			)
+		);
+	}
+
+	#[test]
+	fn register_material_inheritance() {
+		let app = setup(ShaderPlaygroundPlugin::default());
+
+		assert!(app
+			.world()
+			.contains_resource::<InheritedMaterials<StandardMaterial, CustomMaterial>>());
+	}
+
+	#[test]
+	fn register_material_replacement_per_material_pair() {
+		let mut app = setup(ShaderPlaygroundPlugin::default());
+		app.add_plugins(MaterialReplacementPlugin::<CustomMaterial, StandardMaterial>::default());
+
+		assert_eq!(
+			(1, 1),
+			(
+				system_ids(
+					&app,
+					Update,
+					"replace_material<bevy_pbr::pbr_material::StandardMaterial, "
+				)
+				.len(),
+				system_ids(
+					&app,
+					Update,
+					"replace_material<project_zyheeda_bevy_shaders::material::CustomMaterial, "
+				)
+				.len(),
+			)
+		);
+	}
+
+	#[test]
+	fn register_material_time() {
+		let app = setup(ShaderPlaygroundPlugin::default());
+
+		assert_eq!(
+			(true, 1, 1),
+			(
+				app.world().contains_resource::<ShaderTime>(),
+				system_ids(&app, Update, "override_materials").len(),
+				system_ids(&app, Update, "update_shader_time").len(),
+			)
+		);
+	}
+
+	#[test]
+	fn do_not_register_material_time_when_disabled() {
+		let app = setup(ShaderPlaygroundPlugin::default().without_material_time());
+
+		assert_eq!(
+			(false, 0, 0, 1),
+			(
+				app.world().contains_resource::<ShaderTime>(),
+				system_ids(&app, Update, "override_materials").len(),
+				system_ids(&app, Update, "update_shader_time").len(),
+				system_ids(&app, Update, "animate_dissolve").len(),
+			)
+		);
+	}
+
+	#[test]
+	fn register_dissolve() {
+		let app = setup(ShaderPlaygroundPlugin::default());
+
+		assert_eq!(
+			(true, 1),
+			(
+				app.world().contains_resource::<Assets<DissolveMaterial>>(),
+				system_ids(&app, Update, "animate_dissolve").len(),
+			)
+		);
+	}
+
+	#[test]
+	fn do_not_register_dissolve_when_disabled() {
+		let app = setup(ShaderPlaygroundPlugin::default().without_dissolve());
+
+		assert_eq!(
+			(false, 0),
+			(
+				app.world().contains_resource::<Assets<DissolveMaterial>>(),
+				system_ids(&app, Update, "animate_dissolve").len(),
+			)
+		);
+	}
+
+	#[test]
+	fn register_material_time_with_real_time() {
+		let app = setup(ShaderPlaygroundPlugin::default());
+
+		assert_eq!(
+			1,
+			system_ids(&app, Update, "update_shader_time<bevy_time::real::Real>").len()
+		);
+	}
+
+	#[test]
+	fn register_material_time_with_other_clock() {
+		let mut app = setup(ShaderPlaygroundPlugin::default().without_material_time());
+		app.add_plugins(MaterialTimePlugin::<Virtual>::default());
+
+		assert_eq!(
+			(true, 1, 0),
+			(
+				app.world().contains_resource::<ShaderTime>(),
+				system_ids(&app, Update, "update_shader_time<bevy_time::virt::Virtual>").len(),
+				system_ids(&app, Update, "update_shader_time<bevy_time::real::Real>").len(),
+			)
+		);
+	}
+
+	#[test]
+	fn register_camera_controls_in_schedule_and_set() {
+		let app = setup(
+			ShaderPlaygroundPlugin::default()
+				.with_camera_controls(Placement::new(PostUpdate).in_set(MySet)),
+		);
+
+		let ids = [
+			system_ids(&app, PostUpdate, "follow_orbit_target"),
+			system_ids(&app, PostUpdate, "cam_movement"),
+			system_ids(&app, PostUpdate, "smooth_zoom"),
+			system_ids(&app, PostUpdate, "orbit_inertia"),
+			system_ids(&app, PostUpdate, "orbit_motion_inertia"),
+			system_ids(&app, PostUpdate, "send_pan_motion"),
+			system_ids(&app, PostUpdate, "send_camera_input"),
+			system_ids(&app, PostUpdate, "focus"),
+			system_ids(&app, PostUpdate, "bookmark"),
+			system_ids(&app, PostUpdate, "send_touch_motion"),
+			system_ids(&app, PostUpdate, "toggle_projection"),
+			system_ids(&app, PostUpdate, "sync_orthographic_scale"),
+		]
+		.concat();
+
+		assert_eq!(
+			(0, 23, true),
+			(
+				system_ids(&app, Update, "cam_movement").len(),
+				ids.len(),
+				ids.iter().all(|id| is_in_set(&app, PostUpdate, *id, MySet)),
+			)
+		);
+	}
+
+	#[test]
+	fn register_material_replacement_in_schedule_and_set() {
+		let app = setup(
+			ShaderPlaygroundPlugin::default()
+				.with_material_replacement(Placement::new(PostUpdate).in_set(MySet)),
+		);
+
+		let ids = [
+			system_ids(&app, PostUpdate, "refresh_inherited_materials"),
+			system_ids(&app, PostUpdate, "restore_material"),
+			system_ids(&app, PostUpdate, "replace_material"),
+		]
+		.concat();
+
+		assert_eq!(
+			(0, 3, true),
+			(
+				system_ids(&app, Update, "replace_material").len(),
+				ids.len(),
+				ids.iter().all(|id| is_in_set(&app, PostUpdate, *id, MySet)),
+			)
+		);
+	}
+
+	#[test]
+	fn register_material_time_in_schedule_and_set() {
+		let app = setup(
+			ShaderPlaygroundPlugin::default()
+				.with_material_time(Placement::new(PostUpdate).in_set(MySet)),
+		);
+
+		let ids = [
+			system_ids(&app, PostUpdate, "override_materials"),
+			system_ids(&app, PostUpdate, "update_shader_time"),
+		]
+		.concat();
+
+		assert_eq!(
+			(0, 2, true),
+			(
+				system_ids(&app, Update, "update_shader_time").len(),
+				ids.len(),
+				ids.iter().all(|id| is_in_set(&app, PostUpdate, *id, MySet)),
+			)
+		);
+	}
+
+	#[test]
+	fn register_dissolve_in_schedule_and_set() {
+		let app = setup(
+			ShaderPlaygroundPlugin::default()
+				.with_dissolve(Placement::new(PostUpdate).in_set(MySet)),
+		);
+
+		let ids = system_ids(&app, PostUpdate, "animate_dissolve");
+
+		assert_eq!(
+			(0, 1, true),
+			(
+				system_ids(&app, Update, "animate_dissolve").len(),
+				ids.len(),
+				ids.iter().all(|id| is_in_set(&app, PostUpdate, *id, MySet)),
+			)
+		);
+	}
+
+	#[test]
+	fn keep_inserted_camera_settings() {
+		let mut app = App::new();
+		app.add_plugins((MinimalPlugins, AssetPlugin::default(), InputPlugin));
+		app.init_asset::<Shader>();
+		app.insert_resource(CameraZoomSettings {
+			sensitivity: 42.,
+			minimal_distance: 11.,
+			maximal_distance: 12.,
+			pixels_per_line: 13.,
+			lines_per_pinch: 14.,
+			smooth_step: 15.,
+		});
+		app.add_plugins(ShaderPlaygroundPlugin::default());
+
+		let settings = app.world().resource::<CameraZoomSettings>();
+		assert_eq!(
+			(42., 11., 12., 13., 14., 15.),
+			(
+				settings.sensitivity,
+				settings.minimal_distance,
+				settings.maximal_distance,
+				settings.pixels_per_line,
+				settings.lines_per_pinch,
+				settings.smooth_step,
+			)
+		);
+	}
+}
@@ -1,9 +1,70 @@
-use bevy::prelude::Resource;
+use crate::{
+	components::{CameraBookmark, InheritMaterialProperties},
+	tools::shader_type,
+	traits::inherit_material::InheritMaterial,
+};
+use bevy::{
+	input::{
+		gestures::PinchGesture,
+		mouse::{MouseMotion, MouseScrollUnit, MouseWheel},
+	},
+	prelude::*,
+	render::extract_resource::ExtractResource,
+	utils::{HashMap, HashSet},
+};
+use ron::{error::SpannedError, ser::PrettyConfig};
+use serde::{Deserialize, Serialize};
+use std::{collections::BTreeMap, path::PathBuf};
 
 #[derive(Resource, Clone, Copy)]
 pub struct CameraZoomSettings {
 	pub sensitivity: f32,
 	pub minimal_distance: f32,
+	pub maximal_distance: f32,
+	/// Scroll distance of pixel based devices, like touchpads, counted as one line
+	pub pixels_per_line: f32,
+	/// Lines scrolled by a [`PinchGesture`] doubling the distance between fingers
+	pub lines_per_pinch: f32,
+	/// Fraction of the distance a [`SmoothZoom`](crate::components::SmoothZoom) target
+	/// moves per scrolled line, independent from the frame rate
+	pub smooth_step: f32,
+}
+
+impl CameraZoomSettings {
+	/// Lines scrolled by `wheel`
+	pub fn scrolled_lines(&self, wheel: &MouseWheel) -> f32 {
+		match wheel.unit {
+			MouseScrollUnit::Line => wheel.y,
+			MouseScrollUnit::Pixel => wheel.y / self.pixels_per_line,
+		}
+	}
+
+	/// Lines scrolled by `pinch`
+	pub fn pinched_lines(&self, PinchGesture(pinch): &PinchGesture) -> f32 {
+		pinch * self.lines_per_pinch
+	}
+
+	/// The distance to the orbit target after scrolling `lines` at `distance`, kept
+	/// between the minimal and maximal distance.
+	pub fn zoomed_distance(&self, distance: f32, lines: f32, delta: f32) -> f32 {
+		let zoom = lines * distance * self.sensitivity * delta;
+
+		self.clamped_distance(distance - zoom)
+	}
+
+	/// The [`SmoothZoom`](crate::components::SmoothZoom) target distance after scrolling
+	/// `lines` at `distance`, kept between the minimal and maximal distance.
+	pub fn smoothly_zoomed_distance(&self, distance: f32, lines: f32) -> f32 {
+		let zoom = lines * distance * self.smooth_step;
+
+		self.clamped_distance(distance - zoom)
+	}
+
+	fn clamped_distance(&self, distance: f32) -> f32 {
+		distance
+			.min(self.maximal_distance)
+			.max(self.minimal_distance)
+	}
 }
 
 impl Default for CameraZoomSettings {
@@ -11,17 +72,452 @@ impl Default for CameraZoomSettings {
 		Self {
 			sensitivity: 10.,
 			minimal_distance: 3.,
+			maximal_distance: 50.,
+			pixels_per_line: 20.,
+			lines_per_pinch: 6.,
+			smooth_step: 0.15,
 		}
 	}
 }
 
+/// Angles are in radians. Pitch is positive above the orbit target and yaw is 0 in front
+/// of it (along +Z), increasing towards +X.
 #[derive(Resource, Clone, Copy)]
 pub struct CameraRotationSettings {
 	pub sensitivity: f32,
+	pub min_pitch: f32,
+	pub max_pitch: f32,
+	/// `(min, max)` with `min <= max`, may reach past `PI` to limit the yaw around the
+	/// back of the orbit target, like `(PI * 0.75, PI * 1.25)`
+	pub yaw_limits: Option<(f32, f32)>,
+}
+
+impl CameraRotationSettings {
+	/// Yaw and pitch speed in radians per second caused by `motion`
+	pub fn orbit_velocity(&self, motion: &MouseMotion) -> Vec2 {
+		Vec2::new(-motion.delta.x, motion.delta.y) * self.sensitivity
+	}
 }
 
 impl Default for CameraRotationSettings {
 	fn default() -> Self {
-		Self { sensitivity: 0.5 }
+		Self {
+			sensitivity: 0.5,
+			min_pitch: -89_f32.to_radians(),
+			max_pitch: 89_f32.to_radians(),
+			yaw_limits: None,
+		}
+	}
+}
+
+#[derive(Resource, Clone, Copy)]
+pub struct CameraPanSettings {
+	pub sensitivity: f32,
+}
+
+impl Default for CameraPanSettings {
+	fn default() -> Self {
+		Self { sensitivity: 0.05 }
+	}
+}
+
+#[derive(Resource, Clone, Copy)]
+pub struct CameraProjectionSettings {
+	/// Switches orbit cameras between perspective and orthographic projection
+	pub toggle_key: KeyCode,
+}
+
+impl Default for CameraProjectionSettings {
+	fn default() -> Self {
+		Self {
+			toggle_key: KeyCode::KeyO,
+		}
+	}
+}
+
+#[derive(Resource, Clone, Copy)]
+pub struct CameraFocusSettings {
+	/// Seconds the camera takes to reach a new focus
+	pub duration: f32,
+	/// Factor on the bounding sphere radius of a focused entity, to leave some margin
+	pub padding: f32,
+}
+
+impl Default for CameraFocusSettings {
+	fn default() -> Self {
+		Self {
+			duration: 0.5,
+			padding: 1.1,
+		}
+	}
+}
+
+/// Named camera viewpoints, stored and recalled via the hotkeys of the
+/// [`CameraBookmarkSettings`].
+#[derive(Resource, Serialize, Deserialize, Debug, PartialEq, Clone, Default)]
+pub struct CameraBookmarks(pub BTreeMap<String, CameraBookmark>);
+
+impl CameraBookmarks {
+	pub fn to_ron(&self) -> Result<String, ron::Error> {
+		ron::ser::to_string_pretty(self, PrettyConfig::default())
+	}
+
+	pub fn from_ron(ron: &str) -> Result<Self, SpannedError> {
+		ron::from_str(ron)
+	}
+}
+
+#[derive(Resource, Debug, PartialEq, Clone)]
+pub struct CameraBookmarkSettings {
+	/// RON file the [`CameraBookmarks`] are loaded from at startup and saved to when
+	/// storing a bookmark, relative to the working directory
+	pub path: PathBuf,
+	/// Seconds the camera takes to reach a recalled bookmark
+	pub duration: f32,
+	/// Holding any of these while pressing a hotkey stores instead of recalls the bookmark
+	pub store_modifiers: Vec<KeyCode>,
+	/// Keys storing and recalling the bookmark of the given name
+	pub hotkeys: Vec<(KeyCode, String)>,
+}
+
+impl Default for CameraBookmarkSettings {
+	fn default() -> Self {
+		let digits = [
+			KeyCode::Digit1,
+			KeyCode::Digit2,
+			KeyCode::Digit3,
+			KeyCode::Digit4,
+			KeyCode::Digit5,
+			KeyCode::Digit6,
+			KeyCode::Digit7,
+			KeyCode::Digit8,
+			KeyCode::Digit9,
+			KeyCode::Digit0,
+		];
+
+		Self {
+			path: PathBuf::from("assets/camera_bookmarks.ron"),
+			duration: 0.5,
+			store_modifiers: vec![KeyCode::ControlLeft, KeyCode::ControlRight],
+			hotkeys: digits
+				.into_iter()
+				.zip(1..)
+				.map(|(key, number)| (key, (number % 10).to_string()))
+				.collect(),
+		}
+	}
+}
+
+/// Camera movement caused by a bound input at full strength, per second.
+///
+/// Values are sent as [`OrbitMotion`](crate::events::OrbitMotion),
+/// [`PanMotion`](crate::events::PanMotion) and [`ZoomMotion`](crate::events::ZoomMotion)
+/// and thus use the same units as mouse motion and mouse wheel lines.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum CameraInput {
+	Orbit(Vec2),
+	Pan(Vec2),
+	Zoom(f32),
+}
+
+impl CameraInput {
+	pub fn scaled(self, strength: f32) -> Self {
+		match self {
+			CameraInput::Orbit(delta) => CameraInput::Orbit(delta * strength),
+			CameraInput::Pan(delta) => CameraInput::Pan(delta * strength),
+			CameraInput::Zoom(lines) => CameraInput::Zoom(lines * strength),
+		}
+	}
+}
+
+/// Rebindable keyboard and gamepad controls of the orbit camera.
+///
+/// Keys apply their [`CameraInput`] fully while held. Gamepad axes and analog buttons,
+/// like triggers, scale it by their current value.
+#[derive(Resource, Debug, PartialEq, Clone)]
+pub struct CameraInputBindings {
+	pub keys: Vec<(KeyCode, CameraInput)>,
+	pub gamepad_axes: Vec<(GamepadAxisType, CameraInput)>,
+	pub gamepad_buttons: Vec<(GamepadButtonType, CameraInput)>,
+}
+
+impl Default for CameraInputBindings {
+	fn default() -> Self {
+		const ORBIT: f32 = 3.;
+		const PAN: f32 = 20.;
+		const ZOOM: f32 = 0.1;
+
+		Self {
+			keys: vec![
+				(
+					KeyCode::ArrowLeft,
+					CameraInput::Orbit(Vec2::new(-ORBIT, 0.)),
+				),
+				(
+					KeyCode::ArrowRight,
+					CameraInput::Orbit(Vec2::new(ORBIT, 0.)),
+				),
+				(KeyCode::ArrowUp, CameraInput::Orbit(Vec2::new(0., ORBIT))),
+				(
+					KeyCode::ArrowDown,
+					CameraInput::Orbit(Vec2::new(0., -ORBIT)),
+				),
+				(KeyCode::KeyA, CameraInput::Pan(Vec2::new(PAN, 0.))),
+				(KeyCode::KeyD, CameraInput::Pan(Vec2::new(-PAN, 0.))),
+				(KeyCode::KeyW, CameraInput::Pan(Vec2::new(0., PAN))),
+				(KeyCode::KeyS, CameraInput::Pan(Vec2::new(0., -PAN))),
+				(KeyCode::KeyE, CameraInput::Zoom(ZOOM)),
+				(KeyCode::KeyQ, CameraInput::Zoom(-ZOOM)),
+				(KeyCode::Equal, CameraInput::Zoom(ZOOM)),
+				(KeyCode::NumpadAdd, CameraInput::Zoom(ZOOM)),
+				(KeyCode::Minus, CameraInput::Zoom(-ZOOM)),
+				(KeyCode::NumpadSubtract, CameraInput::Zoom(-ZOOM)),
+			],
+			gamepad_axes: vec![
+				(
+					GamepadAxisType::LeftStickX,
+					CameraInput::Orbit(Vec2::new(ORBIT, 0.)),
+				),
+				(
+					GamepadAxisType::LeftStickY,
+					CameraInput::Orbit(Vec2::new(0., ORBIT)),
+				),
+				(
+					GamepadAxisType::RightStickX,
+					CameraInput::Pan(Vec2::new(-PAN, 0.)),
+				),
+				(
+					GamepadAxisType::RightStickY,
+					CameraInput::Pan(Vec2::new(0., PAN)),
+				),
+			],
+			gamepad_buttons: vec![
+				(GamepadButtonType::RightTrigger2, CameraInput::Zoom(ZOOM)),
+				(GamepadButtonType::LeftTrigger2, CameraInput::Zoom(-ZOOM)),
+			],
+		}
+	}
+}
+
+shader_type! {
+	/// The time values shaders read via `shader_time()` from `shaders/shader_time.wgsl`.
+	///
+	/// Set from a [`Time`] clock by the
+	/// [`MaterialTimePlugin`](crate::plugins::MaterialTimePlugin) and extracted into the
+	/// uniform buffer crate materials bind at
+	/// [`SHADER_TIME_BINDING`](crate::material::shader_time_buffer::SHADER_TIME_BINDING).
+	#[derive(Resource, ExtractResource, Debug, PartialEq, Clone, Copy)]
+	pub struct ShaderTime {
+		/// Seconds since startup, wrapping to 0 after one hour
+		pub elapsed_secs: f32,
+		pub delta_secs: f32,
+		/// Frames since startup, wrapping to 0 after [`u32::MAX`]
+		pub frame_count: u32,
+		pub sin_time: f32,
+		pub cos_time: f32,
+	}
+}
+
+impl ShaderTime {
+	pub fn new(elapsed_secs: f32, delta_secs: f32, frame_count: u32) -> Self {
+		Self {
+			elapsed_secs,
+			delta_secs,
+			frame_count,
+			sin_time: elapsed_secs.sin(),
+			cos_time: elapsed_secs.cos(),
+		}
+	}
+}
+
+impl Default for ShaderTime {
+	fn default() -> Self {
+		Self::new(0., 0., 0)
+	}
+}
+
+type InheritanceKey<TSource, TTarget> = (
+	AssetId<TTarget>,
+	AssetId<TSource>,
+	InheritMaterialProperties,
+);
+
+/// Replacement materials derived via [`InheritMaterial`], shared between all meshes
+/// with the same replacement, replaced material and [`InheritMaterialProperties`].
+///
+/// Initialize it to enable [`InheritMaterialProperties`] for a material pair.
+///
+/// Derived materials are only kept alive by the meshes using them and are forgotten
+/// once dropped.
+#[derive(Resource)]
+pub struct InheritedMaterials<TSource: Material, TTarget: Material> {
+	inherit: fn(&TTarget, &TSource, InheritMaterialProperties) -> TTarget,
+	derived: HashMap<InheritanceKey<TSource, TTarget>, AssetId<TTarget>>,
+}
+
+impl<TSource, TTarget> InheritedMaterials<TSource, TTarget>
+where
+	TSource: Material,
+	TTarget: Material,
+{
+	/// Returns `None` while `template` or `source` are not loaded.
+	pub fn derive(
+		&mut self,
+		template: &Handle<TTarget>,
+		source: &Handle<TSource>,
+		properties: InheritMaterialProperties,
+		sources: &Assets<TSource>,
+		targets: &mut Assets<TTarget>,
+	) -> Option<Handle<TTarget>> {
+		let key = (template.id(), source.id(), properties);
+		let cached = self.derived.get(&key);
+
+		if let Some(derived) = cached.and_then(|id| targets.get_strong_handle(*id)) {
+			return Some(derived);
+		}
+
+		let derived = (self.inherit)(targets.get(template)?, sources.get(source)?, properties);
+		let derived = targets.add(derived);
+		self.derived.insert(key, derived.id());
+
+		Some(derived)
+	}
+
+	/// Re-derives materials whose template or source was modified and forgets those
+	/// whose template, source or derived material was removed or dropped.
+	pub fn refresh<'a>(
+		&mut self,
+		source_events: impl IntoIterator<Item = &'a AssetEvent<TSource>>,
+		target_events: impl IntoIterator<Item = &'a AssetEvent<TTarget>>,
+		sources: &Assets<TSource>,
+		targets: &mut Assets<TTarget>,
+	) {
+		let mut modified_sources = HashSet::new();
+		let mut removed_sources = HashSet::new();
+		let mut modified_targets = HashSet::new();
+		let mut removed_targets = HashSet::new();
+
+		for event in source_events {
+			match event {
+				AssetEvent::Modified { id } => modified_sources.insert(*id),
+				AssetEvent::Removed { id } => removed_sources.insert(*id),
+				_ => continue,
+			};
+		}
+		for event in target_events {
+			match event {
+				AssetEvent::Modified { id } => modified_targets.insert(*id),
+				AssetEvent::Removed { id } | AssetEvent::Unused { id } => {
+					removed_targets.insert(*id)
+				}
+				_ => continue,
+			};
+		}
+
+		self.derived.retain(|(template, source, _), derived| {
+			!removed_targets.contains(template)
+				&& !removed_sources.contains(source)
+				&& !removed_targets.contains(derived)
+		});
+
+		for ((template, source, properties), derived) in &self.derived {
+			if !modified_targets.contains(template) && !modified_sources.contains(source) {
+				continue;
+			}
+			let (Some(template), Some(source)) = (targets.get(*template), sources.get(*source))
+			else {
+				continue;
+			};
+
+			let material = (self.inherit)(template, source, *properties);
+			targets.insert(*derived, material);
+		}
+	}
+}
+
+impl<TSource, TTarget> Default for InheritedMaterials<TSource, TTarget>
+where
+	TSource: Material,
+	TTarget: Material + InheritMaterial<TSource>,
+{
+	fn default() -> Self {
+		Self {
+			inherit: TTarget::inherit,
+			derived: HashMap::default(),
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	fn bookmarks() -> CameraBookmarks {
+		CameraBookmarks(BTreeMap::from([
+			(
+				"1".to_owned(),
+				CameraBookmark::new(
+					&Transform::from_xyz(0., 2., 5.).looking_at(Vec3::ZERO, Vec3::Y),
+					Vec3::ZERO,
+				),
+			),
+			(
+				"front of shield".to_owned(),
+				CameraBookmark {
+					translation: Vec3::new(-1.5, 0.25, 0.125),
+					rotation: Quat::from_rotation_y(0.3),
+					anchor: Vec3::new(-1., 0.5, 0.),
+					distance: 0.75,
+				},
+			),
+		]))
+	}
+
+	#[test]
+	fn bookmarks_round_trip() {
+		let bookmarks = bookmarks();
+
+		let ron = bookmarks.to_ron().unwrap();
+
+		assert_eq!(Ok(bookmarks), CameraBookmarks::from_ron(&ron));
+	}
+
+	#[test]
+	fn empty_bookmarks_round_trip() {
+		let bookmarks = CameraBookmarks::default();
+
+		let ron = bookmarks.to_ron().unwrap();
+
+		assert_eq!(Ok(bookmarks), CameraBookmarks::from_ron(&ron));
+	}
+
+	#[test]
+	fn bookmarks_from_handwritten_ron() {
+		let ron = r#"({
+			"top": (
+				translation: (0.0, 10.0, 0.0),
+				rotation: (0.0, 0.0, 0.0, 1.0),
+				anchor: (0.0, 0.0, 0.0),
+				distance: 10.0,
+			),
+		})"#;
+
+		assert_eq!(
+			Ok(CameraBookmarks(BTreeMap::from([(
+				"top".to_owned(),
+				CameraBookmark {
+					translation: Vec3::new(0., 10., 0.),
+					rotation: Quat::IDENTITY,
+					anchor: Vec3::ZERO,
+					distance: 10.,
+				}
+			)]))),
+			CameraBookmarks::from_ron(ron)
+		);
+	}
+
+	#[test]
+	fn bookmarks_from_invalid_ron() {
+		assert!(CameraBookmarks::from_ron("({\"top\": ()})").is_err());
 	}
 }
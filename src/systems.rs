@@ -1,4 +1,25 @@
+pub mod animate_bookmark;
+pub mod animate_dissolve;
+pub mod animate_focus;
+pub mod apply_orbit_inertia;
+pub mod bookmark_hotkeys;
 pub mod cam_movement;
+pub mod feed_orbit_inertia;
+pub mod feed_orbit_motion_inertia;
+pub mod focus_on;
+pub mod follow_orbit_target;
 pub mod holding_button;
-pub mod replace_standard_material;
-pub mod set_material_time;
+pub mod load_camera_bookmarks;
+pub mod override_materials;
+pub mod refresh_inherited_materials;
+pub mod replace_material;
+pub mod restore_material;
+pub mod send_camera_input;
+pub mod send_pan_motion;
+pub mod send_touch_motion;
+pub mod smooth_zoom;
+pub mod stop_orbit_inertia;
+pub mod sync_orthographic_scale;
+pub mod toggle_projection;
+pub mod update_shader_time;
+pub mod write_shader_time_buffer;
@@ -0,0 +1,138 @@
+use crate::components::{BookmarkTransition, OrbitTarget};
+use bevy::prelude::*;
+
+/// Moves cameras along their [`BookmarkTransition`], keeping the kind of their
+/// [`OrbitTarget`] anchor, and removes it once finished.
+pub fn animate_bookmark(
+	mut commands: Commands,
+	time: Res<Time<Real>>,
+	mut cameras: Query<(
+		Entity,
+		&mut Transform,
+		&mut OrbitTarget,
+		&mut BookmarkTransition,
+	)>,
+	transforms: Query<&GlobalTransform>,
+) {
+	let delta = time.delta_seconds();
+
+	for (entity, mut transform, mut target, mut transition) in &mut cameras {
+		transition.elapsed += delta;
+		let bookmark = transition.value();
+		transform.translation = bookmark.translation;
+		transform.rotation = bookmark.rotation;
+		target.move_to(bookmark.anchor, &transforms);
+
+		if transition.is_finished() {
+			commands.entity(entity).remove::<BookmarkTransition>();
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::{
+		components::CameraBookmark,
+		tools::test_tools::{frame, tick_time},
+		traits::lerp::Lerp,
+	};
+	use std::time::Duration;
+
+	fn setup() -> App {
+		let mut app = App::new();
+		app.init_resource::<Time<Real>>();
+		app.add_systems(Update, animate_bookmark);
+
+		tick_time(&mut app, Duration::ZERO);
+		app
+	}
+
+	fn transition() -> BookmarkTransition {
+		BookmarkTransition::new(
+			CameraBookmark::new(&Transform::from_xyz(0., 0., 2.), Vec3::ZERO),
+			CameraBookmark::new(
+				&Transform::from_xyz(5., 0., 0.).looking_at(Vec3::X, Vec3::Y),
+				Vec3::X,
+			),
+			2.,
+		)
+	}
+
+	fn spawn_camera(app: &mut App) -> Entity {
+		app.world_mut()
+			.spawn((
+				Transform::from_xyz(0., 0., 2.),
+				OrbitTarget::point(Vec3::ZERO),
+				transition(),
+			))
+			.id()
+	}
+
+	#[test]
+	fn move_along_transition() {
+		let mut app = setup();
+		let camera = spawn_camera(&mut app);
+
+		frame(&mut app, Duration::from_secs(1));
+
+		let camera = app.world().entity(camera);
+		let expected = transition().from.lerp(&transition().to, 0.5);
+		assert_eq!(
+			(
+				expected.translation,
+				expected.rotation,
+				Some(&OrbitTarget::point(expected.anchor))
+			),
+			(
+				camera.get::<Transform>().unwrap().translation,
+				camera.get::<Transform>().unwrap().rotation,
+				camera.get::<OrbitTarget>(),
+			)
+		);
+	}
+
+	#[test]
+	fn keep_following_target_entity() {
+		let mut app = setup();
+		let model = app
+			.world_mut()
+			.spawn(GlobalTransform::from_xyz(0., 0., 0.))
+			.id();
+		let camera = spawn_camera(&mut app);
+		app.world_mut()
+			.entity_mut(camera)
+			.insert(OrbitTarget::entity(model));
+
+		frame(&mut app, Duration::from_secs(1));
+
+		let expected = transition().from.lerp(&transition().to, 0.5);
+		assert_eq!(
+			Some(&OrbitTarget::entity(model).with_offset(expected.anchor)),
+			app.world().entity(camera).get::<OrbitTarget>()
+		);
+	}
+
+	#[test]
+	fn arrive_at_bookmark_and_remove_transition() {
+		let mut app = setup();
+		let camera = spawn_camera(&mut app);
+
+		frame(&mut app, Duration::from_secs(2));
+
+		let camera = app.world().entity(camera);
+		let to = transition().to;
+		assert_eq!(
+			(
+				Transform::from_translation(to.translation).with_rotation(to.rotation),
+				Some(&OrbitTarget::point(Vec3::X)),
+				false,
+			),
+			(
+				*camera.get::<Transform>().unwrap(),
+				camera.get::<OrbitTarget>(),
+				camera.contains::<BookmarkTransition>(),
+			)
+		);
+	}
+}
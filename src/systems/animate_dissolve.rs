@@ -0,0 +1,145 @@
+use crate::{
+	components::{DespawnDissolved, DissolveTransition},
+	material::dissolve_material::DissolveMaterial,
+};
+use bevy::prelude::*;
+
+/// Moves the dissolve threshold of materials along their entity's [`DissolveTransition`]
+/// and removes it once finished, despawning entities marked with [`DespawnDissolved`].
+pub fn animate_dissolve(
+	mut commands: Commands,
+	time: Res<Time<Real>>,
+	mut materials: ResMut<Assets<DissolveMaterial>>,
+	mut transitions: Query<(
+		Entity,
+		&Handle<DissolveMaterial>,
+		&mut DissolveTransition,
+		Has<DespawnDissolved>,
+	)>,
+) {
+	let delta = time.delta_seconds();
+
+	for (entity, handle, mut dissolve, despawn) in &mut transitions {
+		let Some(material) = materials.get_mut(handle) else {
+			continue;
+		};
+
+		let DissolveTransition(transition) = dissolve.as_mut();
+		transition.elapsed += delta;
+		material.dissolve.threshold = transition.value();
+
+		if !transition.is_finished() {
+			continue;
+		}
+
+		match despawn {
+			true => commands.entity(entity).despawn_recursive(),
+			false => {
+				commands.entity(entity).remove::<DissolveTransition>();
+			}
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::{
+		assert_approx_eq,
+		components::Transition,
+		tools::test_tools::{frame, tick_time},
+	};
+	use std::time::Duration;
+
+	fn setup() -> App {
+		let mut app = App::new();
+		app.init_resource::<Time<Real>>();
+		app.init_resource::<Assets<DissolveMaterial>>();
+		app.add_systems(Update, animate_dissolve);
+
+		tick_time(&mut app, Duration::ZERO);
+		app
+	}
+
+	fn spawn(app: &mut App, transition: impl Bundle) -> (Entity, Handle<DissolveMaterial>) {
+		let handle = app
+			.world_mut()
+			.resource_mut::<Assets<DissolveMaterial>>()
+			.add(DissolveMaterial::default());
+		let entity = app.world_mut().spawn((handle.clone(), transition)).id();
+
+		(entity, handle)
+	}
+
+	fn threshold(app: &App, handle: &Handle<DissolveMaterial>) -> f32 {
+		let materials = app.world().resource::<Assets<DissolveMaterial>>();
+		materials.get(handle).unwrap().dissolve.threshold
+	}
+
+	#[test]
+	fn move_threshold_over_time() {
+		let mut app = setup();
+		let (_, handle) = spawn(&mut app, DissolveTransition::appear(2.));
+
+		frame(&mut app, Duration::from_millis(500));
+		let quarter = threshold(&app, &handle);
+		frame(&mut app, Duration::from_millis(500));
+		let half = threshold(&app, &handle);
+
+		assert_approx_eq!(0.75, quarter, 0.00001);
+		assert_approx_eq!(0.5, half, 0.00001);
+	}
+
+	#[test]
+	fn end_on_target_threshold() {
+		let mut app = setup();
+		let (_, handle) = spawn(&mut app, DissolveTransition::new(0.2, 0.8, 1.));
+
+		frame(&mut app, Duration::from_secs(3));
+
+		assert_eq!(0.8, threshold(&app, &handle));
+	}
+
+	#[test]
+	fn ignore_other_transitions() {
+		let mut app = setup();
+		let (_, handle) = spawn(&mut app, Transition::new(0.2, 0.8, 1.));
+
+		frame(&mut app, Duration::from_secs(3));
+
+		assert_eq!(0., threshold(&app, &handle));
+	}
+
+	#[test]
+	fn remove_when_finished() {
+		let mut app = setup();
+		let (entity, _) = spawn(&mut app, DissolveTransition::appear(2.));
+
+		frame(&mut app, Duration::from_secs(1));
+		let during = app.world().entity(entity).contains::<DissolveTransition>();
+		frame(&mut app, Duration::from_secs(1));
+		let after = app.world().entity(entity).contains::<DissolveTransition>();
+
+		assert_eq!((true, false), (during, after));
+	}
+
+	#[test]
+	fn despawn_when_disappeared() {
+		let mut app = setup();
+		let (entity, _) = spawn(&mut app, DissolveTransition::disappear(2.));
+		let child = app.world_mut().spawn_empty().set_parent(entity).id();
+
+		frame(&mut app, Duration::from_secs(1));
+		let during = app.world().get_entity(entity).is_some();
+		frame(&mut app, Duration::from_secs(1));
+
+		assert_eq!(
+			(true, false, false),
+			(
+				during,
+				app.world().get_entity(entity).is_some(),
+				app.world().get_entity(child).is_some(),
+			)
+		);
+	}
+}
@@ -0,0 +1,144 @@
+use crate::components::{Focus, FocusTransition, OrbitTarget};
+use bevy::prelude::*;
+
+/// Moves cameras along their [`FocusTransition`], keeping their orbit direction and the
+/// kind of their [`OrbitTarget`] anchor, and removes it once finished.
+pub fn animate_focus(
+	mut commands: Commands,
+	time: Res<Time<Real>>,
+	mut cameras: Query<(
+		Entity,
+		&mut Transform,
+		&mut OrbitTarget,
+		&mut FocusTransition,
+	)>,
+	transforms: Query<&GlobalTransform>,
+) {
+	let delta = time.delta_seconds();
+
+	for (entity, mut transform, mut target, mut transition) in &mut cameras {
+		let Some(anchor) = target.position(&transforms) else {
+			continue;
+		};
+		let direction = (transform.translation - anchor)
+			.try_normalize()
+			.unwrap_or(*transform.back());
+
+		transition.elapsed += delta;
+		let Focus { anchor, distance } = transition.value();
+		target.move_to(anchor, &transforms);
+		transform.translation = anchor + direction * distance;
+
+		if transition.is_finished() {
+			commands.entity(entity).remove::<FocusTransition>();
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::{
+		assert_approx_eq,
+		tools::test_tools::{frame, tick_time},
+	};
+	use std::time::Duration;
+
+	const TOLERANCE: Vec3 = Vec3::splat(0.00001);
+
+	fn setup() -> App {
+		let mut app = App::new();
+		app.init_resource::<Time<Real>>();
+		app.add_systems(Update, animate_focus);
+
+		tick_time(&mut app, Duration::ZERO);
+		app
+	}
+
+	fn spawn_camera(app: &mut App) -> Entity {
+		app.world_mut()
+			.spawn((
+				Transform::from_xyz(0., 0., 10.),
+				OrbitTarget::point(Vec3::ZERO),
+				FocusTransition::new(
+					Focus {
+						anchor: Vec3::ZERO,
+						distance: 10.,
+					},
+					Focus {
+						anchor: Vec3::new(2., 0., 0.),
+						distance: 20.,
+					},
+					2.,
+				),
+			))
+			.id()
+	}
+
+	#[test]
+	fn move_halfway() {
+		let mut app = setup();
+		let camera = spawn_camera(&mut app);
+
+		frame(&mut app, Duration::from_secs(1));
+
+		let camera = app.world().entity(camera);
+		assert_approx_eq!(
+			Vec3::new(1., 0., 15.),
+			camera.get::<Transform>().unwrap().translation,
+			TOLERANCE
+		);
+		assert_eq!(
+			Some(&OrbitTarget::point(Vec3::new(1., 0., 0.))),
+			camera.get::<OrbitTarget>()
+		);
+	}
+
+	#[test]
+	fn keep_orbit_direction() {
+		let mut app = setup();
+		let camera = spawn_camera(&mut app);
+		app.world_mut()
+			.entity_mut(camera)
+			.insert(Transform::from_xyz(0., 10., 0.));
+
+		frame(&mut app, Duration::from_secs(2));
+
+		let transform = app.world().entity(camera).get::<Transform>().unwrap();
+		assert_approx_eq!(Vec3::new(2., 20., 0.), transform.translation, TOLERANCE);
+	}
+
+	#[test]
+	fn keep_following_target_entity() {
+		let mut app = setup();
+		let model = app
+			.world_mut()
+			.spawn(GlobalTransform::from_xyz(0., 0., 0.))
+			.id();
+		let camera = spawn_camera(&mut app);
+		app.world_mut()
+			.entity_mut(camera)
+			.insert(OrbitTarget::entity(model));
+
+		frame(&mut app, Duration::from_secs(1));
+
+		let camera = app.world().entity(camera);
+		assert_eq!(
+			Some(&OrbitTarget::entity(model).with_offset(Vec3::new(1., 0., 0.))),
+			camera.get::<OrbitTarget>()
+		);
+	}
+
+	#[test]
+	fn remove_when_finished() {
+		let mut app = setup();
+		let camera = spawn_camera(&mut app);
+
+		frame(&mut app, Duration::from_secs(1));
+		let during = app.world().entity(camera).contains::<FocusTransition>();
+		frame(&mut app, Duration::from_secs(1));
+		let after = app.world().entity(camera).contains::<FocusTransition>();
+
+		assert_eq!((true, false), (during, after));
+	}
+}
@@ -0,0 +1,308 @@
+use crate::{
+	components::{OrbitInertia, OrbitTarget},
+	resources::CameraRotationSettings,
+	traits::movement::{Anchor, Orbit},
+};
+use bevy::prelude::*;
+
+const RESTING_SPEED: f32 = 0.0001;
+
+type Cameras<'w, 's, 'a> = Query<
+	'w,
+	's,
+	(
+		Entity,
+		&'a mut Transform,
+		Option<&'a OrbitTarget>,
+		&'a mut OrbitInertia,
+		Option<&'a mut Orbit>,
+	),
+	With<Camera>,
+>;
+
+/// Rotates cameras by their [`OrbitInertia`] velocity and damps it.
+///
+/// Cameras without an [`OrbitTarget`] rotate around the default target.
+pub fn apply_orbit_inertia(
+	mut commands: Commands,
+	time: Res<Time<Real>>,
+	settings: Res<CameraRotationSettings>,
+	mut cameras: Cameras,
+	transforms: Query<&GlobalTransform>,
+) {
+	let delta = time.delta_seconds();
+
+	for (entity, mut transform, target, mut inertia, orbit) in &mut cameras {
+		if inertia.velocity == Vec2::ZERO {
+			continue;
+		}
+		let target = target.copied().unwrap_or_default();
+		let Some(anchor) = target.position(&transforms).map(Anchor) else {
+			continue;
+		};
+		let mut moved_orbit = match orbit.as_deref() {
+			Some(orbit) => *orbit,
+			None => Orbit::from_transform(&transform, anchor),
+		};
+		moved_orbit.sync(&transform, anchor);
+
+		if moved_orbit.distance > 0. {
+			let rotation = inertia.velocity * delta;
+			moved_orbit.rotate(rotation.x, rotation.y, *settings);
+			moved_orbit.apply(&mut transform, anchor);
+		}
+
+		match orbit {
+			Some(mut orbit) => {
+				orbit.set_if_neq(moved_orbit);
+			}
+			None => {
+				commands.entity(entity).insert(moved_orbit);
+			}
+		}
+
+		let damping = (-inertia.damping * delta).exp();
+		inertia.velocity *= damping;
+		if inertia.velocity.length() < RESTING_SPEED {
+			inertia.velocity = Vec2::ZERO;
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::{
+		assert_approx_eq,
+		tools::test_tools::{frame, tick_time},
+	};
+	use std::{f32::consts::PI, time::Duration};
+
+	fn setup() -> App {
+		let mut app = App::new();
+		app.init_resource::<Time<Real>>();
+		app.insert_resource(CameraRotationSettings::default());
+		app.add_systems(Update, apply_orbit_inertia);
+
+		tick_time(&mut app, Duration::ZERO);
+		app
+	}
+
+	fn spawn_camera(app: &mut App, inertia: OrbitInertia) -> Entity {
+		app.world_mut()
+			.spawn((
+				Transform::from_xyz(0., 0., 2.).looking_at(Vec3::ZERO, Vec3::Y),
+				Camera::default(),
+				OrbitTarget::point(Vec3::ZERO),
+				inertia,
+			))
+			.id()
+	}
+
+	fn orbit_of(app: &App, camera: Entity) -> Orbit {
+		let transform = app.world().entity(camera).get::<Transform>().unwrap();
+		Orbit::from_transform(transform, Anchor(Vec3::ZERO))
+	}
+
+	#[derive(Resource, Default)]
+	struct MovedCameras(Vec<Entity>);
+
+	fn count_moved_cameras(
+		cameras: Query<Entity, Changed<Transform>>,
+		mut moved: ResMut<MovedCameras>,
+	) {
+		moved.0.extend(cameras.iter());
+	}
+
+	fn inertia_of(app: &App, camera: Entity) -> OrbitInertia {
+		*app.world().entity(camera).get::<OrbitInertia>().unwrap()
+	}
+
+	#[test]
+	fn rotate_by_velocity() {
+		let mut app = setup();
+		let camera = spawn_camera(
+			&mut app,
+			OrbitInertia {
+				damping: 0.,
+				velocity: Vec2::new(PI / 4., PI / 8.),
+			},
+		);
+
+		frame(&mut app, Duration::from_secs(1));
+
+		let orbit = orbit_of(&app, camera);
+		assert_approx_eq!(
+			Vec3::new(PI / 4., PI / 8., 2.),
+			Vec3::new(orbit.yaw, orbit.pitch, orbit.distance),
+			Vec3::splat(0.0001)
+		);
+	}
+
+	#[test]
+	fn rotate_around_default_target_without_target() {
+		let mut app = setup();
+		let anchor = Vec3::new(0., 0.5, 0.);
+		let camera = app
+			.world_mut()
+			.spawn((
+				Transform::from_translation(anchor + Vec3::Z * 2.).looking_at(anchor, Vec3::Y),
+				Camera::default(),
+				OrbitInertia {
+					damping: 0.,
+					velocity: Vec2::new(PI / 4., 0.),
+				},
+			))
+			.id();
+
+		frame(&mut app, Duration::from_secs(1));
+
+		let transform = app.world().entity(camera).get::<Transform>().unwrap();
+		let orbit = Orbit::from_transform(transform, Anchor(anchor));
+		assert_approx_eq!(
+			Vec3::new(PI / 4., 0., 2.),
+			Vec3::new(orbit.yaw, orbit.pitch, orbit.distance),
+			Vec3::splat(0.0001)
+		);
+	}
+
+	#[test]
+	fn damp_velocity() {
+		let mut app = setup();
+		let camera = spawn_camera(
+			&mut app,
+			OrbitInertia {
+				damping: 2_f32.ln(),
+				velocity: Vec2::new(0.4, 0.2),
+			},
+		);
+
+		frame(&mut app, Duration::from_secs(1));
+		frame(&mut app, Duration::from_secs(1));
+
+		assert_approx_eq!(
+			Vec3::new(0.1, 0.05, 0.),
+			inertia_of(&app, camera).velocity.extend(0.),
+			Vec3::splat(0.0001)
+		);
+	}
+
+	#[test]
+	fn coast_by_damped_velocity() {
+		let mut app = setup();
+		let camera = spawn_camera(
+			&mut app,
+			OrbitInertia {
+				damping: 2_f32.ln(),
+				velocity: Vec2::new(0.4, 0.),
+			},
+		);
+
+		frame(&mut app, Duration::from_secs(1));
+		frame(&mut app, Duration::from_secs(1));
+
+		assert_approx_eq!(0.6, orbit_of(&app, camera).yaw, 0.0001);
+	}
+
+	#[test]
+	fn clamp_pitch() {
+		let mut app = setup();
+		app.insert_resource(CameraRotationSettings {
+			max_pitch: PI / 4.,
+			..default()
+		});
+		let camera = spawn_camera(
+			&mut app,
+			OrbitInertia {
+				damping: 0.,
+				velocity: Vec2::new(0., PI),
+			},
+		);
+
+		frame(&mut app, Duration::from_secs(1));
+
+		assert_approx_eq!(PI / 4., orbit_of(&app, camera).pitch, 0.0001);
+	}
+
+	#[test]
+	fn clamp_yaw_to_limits_wrapping_around_pi() {
+		let mut app = setup();
+		app.insert_resource(CameraRotationSettings {
+			yaw_limits: Some((PI * 0.75, PI * 1.25)),
+			..default()
+		});
+		let camera = app
+			.world_mut()
+			.spawn((
+				Transform::from_xyz(0., 0., -2.).looking_at(Vec3::ZERO, Vec3::Y),
+				Camera::default(),
+				OrbitTarget::point(Vec3::ZERO),
+				OrbitInertia {
+					damping: 0.,
+					velocity: Vec2::new(PI / 8., 0.),
+				},
+			))
+			.id();
+
+		frame(&mut app, Duration::from_secs(1));
+		frame(&mut app, Duration::from_secs(1));
+		frame(&mut app, Duration::from_secs(1));
+
+		let orbit = app.world().entity(camera).get::<Orbit>().unwrap();
+		assert_approx_eq!(PI * 1.25, orbit.yaw, 0.0001);
+	}
+
+	#[test]
+	fn keep_orbit_yaw_turned_past_pi() {
+		let mut app = setup();
+		let camera = app
+			.world_mut()
+			.spawn((
+				Transform::from_xyz(0., 0., -2.).looking_at(Vec3::ZERO, Vec3::Y),
+				Camera::default(),
+				OrbitTarget::point(Vec3::ZERO),
+				OrbitInertia {
+					damping: 0.,
+					velocity: Vec2::new(PI / 4., 0.),
+				},
+			))
+			.id();
+
+		frame(&mut app, Duration::from_secs(1));
+		frame(&mut app, Duration::from_secs(1));
+
+		let orbit = app.world().entity(camera).get::<Orbit>().unwrap();
+		assert_approx_eq!(PI * 1.5, orbit.yaw, 0.0001);
+	}
+
+	#[test]
+	fn come_to_rest() {
+		let mut app = setup();
+		let camera = spawn_camera(
+			&mut app,
+			OrbitInertia {
+				damping: 10.,
+				velocity: Vec2::new(0.4, 0.2),
+			},
+		);
+
+		frame(&mut app, Duration::from_secs(2));
+
+		assert_eq!(Vec2::ZERO, inertia_of(&app, camera).velocity);
+	}
+
+	#[test]
+	fn do_not_move_resting_camera() {
+		let mut app = setup();
+		let camera = spawn_camera(&mut app, OrbitInertia::new(1.));
+
+		app.init_resource::<MovedCameras>();
+		app.add_systems(Update, count_moved_cameras.after(apply_orbit_inertia));
+
+		frame(&mut app, Duration::ZERO);
+		app.world_mut().resource_mut::<MovedCameras>().0.clear();
+		frame(&mut app, Duration::from_secs(1));
+
+		assert!(!app.world().resource::<MovedCameras>().0.contains(&camera));
+	}
+}
@@ -0,0 +1,285 @@
+use crate::{
+	components::{
+		BookmarkTransition,
+		CameraBookmark,
+		FocusTransition,
+		OrbitInertia,
+		OrbitTarget,
+		SmoothZoom,
+	},
+	resources::{CameraBookmarkSettings, CameraBookmarks},
+};
+use bevy::prelude::*;
+use std::fs;
+
+type Cameras<'a> = (
+	Entity,
+	&'a Transform,
+	Option<&'a OrbitTarget>,
+	Option<&'a mut SmoothZoom>,
+	Option<&'a mut OrbitInertia>,
+);
+
+/// Stores the viewpoint of the orbit camera as the bookmark of a pressed hotkey while a
+/// store modifier is held and saves all bookmarks to the RON file, otherwise starts a
+/// [`BookmarkTransition`] to the bookmark.
+///
+/// Storing requires a single camera, recalling moves all of them. Cameras without an
+/// [`OrbitTarget`] use the default target, which gets inserted when recalling.
+pub fn bookmark_hotkeys(
+	mut commands: Commands,
+	settings: Res<CameraBookmarkSettings>,
+	keys: Res<ButtonInput<KeyCode>>,
+	mut bookmarks: ResMut<CameraBookmarks>,
+	mut cameras: Query<Cameras, With<Camera>>,
+	transforms: Query<&GlobalTransform>,
+) {
+	let storing = keys.any_pressed(settings.store_modifiers.iter().copied());
+	let names = settings
+		.hotkeys
+		.iter()
+		.filter(|(key, _)| keys.just_pressed(*key))
+		.map(|(_, name)| name);
+	let mut stored = false;
+
+	for name in names {
+		if storing {
+			let Ok((_, transform, target, ..)) = cameras.get_single() else {
+				continue;
+			};
+			let target = target.copied().unwrap_or_default();
+			let Some(anchor) = target.position(&transforms) else {
+				continue;
+			};
+			bookmarks
+				.0
+				.insert(name.clone(), CameraBookmark::new(transform, anchor));
+			stored = true;
+			continue;
+		}
+
+		let Some(bookmark) = bookmarks.0.get(name) else {
+			continue;
+		};
+		for (entity, transform, target, smooth_zoom, inertia) in &mut cameras {
+			let orbit_target = target.copied().unwrap_or_default();
+			let Some(anchor) = orbit_target.position(&transforms) else {
+				continue;
+			};
+			let from = CameraBookmark::new(transform, anchor);
+
+			if let Some(mut smooth_zoom) = smooth_zoom {
+				smooth_zoom.target_distance = None;
+			}
+			if let Some(mut inertia) = inertia {
+				inertia.velocity = Vec2::ZERO;
+			}
+			let mut camera = commands.entity(entity);
+			camera
+				.remove::<FocusTransition>()
+				.insert(BookmarkTransition::new(from, *bookmark, settings.duration));
+			if target.is_none() {
+				camera.insert(orbit_target);
+			}
+		}
+	}
+
+	if stored {
+		save(&settings, &bookmarks);
+	}
+}
+
+fn save(settings: &CameraBookmarkSettings, bookmarks: &CameraBookmarks) {
+	let ron = match bookmarks.to_ron() {
+		Ok(ron) => ron,
+		Err(error) => {
+			error!("Cannot serialize camera bookmarks: {error}");
+			return;
+		}
+	};
+
+	if let Err(error) = fs::write(&settings.path, ron) {
+		error!("Cannot write camera bookmarks {:?}: {error}", settings.path);
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use std::{collections::BTreeMap, path::PathBuf};
+	use uuid::Uuid;
+
+	fn temp_path() -> PathBuf {
+		std::env::temp_dir().join(format!("camera_bookmarks_{}.ron", Uuid::new_v4()))
+	}
+
+	fn setup(path: PathBuf, bookmarks: CameraBookmarks) -> App {
+		let mut app = App::new();
+		app.init_resource::<ButtonInput<KeyCode>>();
+		app.insert_resource(CameraBookmarkSettings {
+			path,
+			duration: 2.,
+			store_modifiers: vec![KeyCode::ControlLeft],
+			hotkeys: vec![(KeyCode::Digit1, "1".to_owned())],
+		});
+		app.insert_resource(bookmarks);
+		app.add_systems(Update, bookmark_hotkeys);
+
+		app
+	}
+
+	fn spawn_camera(app: &mut App, transform: Transform) -> Entity {
+		app.world_mut()
+			.spawn((Camera::default(), transform, OrbitTarget::point(Vec3::ZERO)))
+			.id()
+	}
+
+	fn press(app: &mut App, keys: &[KeyCode]) {
+		let mut input = app.world_mut().resource_mut::<ButtonInput<KeyCode>>();
+		for key in keys {
+			input.press(*key);
+		}
+	}
+
+	fn bookmark(distance: f32) -> CameraBookmark {
+		CameraBookmark {
+			translation: Vec3::new(0., 0., distance),
+			rotation: Quat::IDENTITY,
+			anchor: Vec3::ZERO,
+			distance,
+		}
+	}
+
+	#[test]
+	fn store_bookmark() {
+		let path = temp_path();
+		let mut app = setup(path.clone(), CameraBookmarks::default());
+		spawn_camera(&mut app, Transform::from_xyz(0., 0., 4.));
+
+		press(&mut app, &[KeyCode::ControlLeft, KeyCode::Digit1]);
+		app.update();
+		let saved = fs::read_to_string(&path).unwrap();
+		fs::remove_file(path).unwrap();
+
+		let expected = CameraBookmarks(BTreeMap::from([("1".to_owned(), bookmark(4.))]));
+		assert_eq!(
+			(&expected, Ok(expected.clone())),
+			(
+				app.world().resource::<CameraBookmarks>(),
+				CameraBookmarks::from_ron(&saved)
+			)
+		);
+	}
+
+	#[test]
+	fn do_not_store_with_multiple_cameras() {
+		let path = temp_path();
+		let mut app = setup(path.clone(), CameraBookmarks::default());
+		spawn_camera(&mut app, Transform::from_xyz(0., 0., 4.));
+		spawn_camera(&mut app, Transform::from_xyz(0., 0., 5.));
+
+		press(&mut app, &[KeyCode::ControlLeft, KeyCode::Digit1]);
+		app.update();
+
+		assert_eq!(
+			(&CameraBookmarks::default(), false),
+			(app.world().resource::<CameraBookmarks>(), path.exists())
+		);
+	}
+
+	#[test]
+	fn recall_bookmark() {
+		let path = temp_path();
+		let bookmarks = CameraBookmarks(BTreeMap::from([("1".to_owned(), bookmark(8.))]));
+		let mut app = setup(path.clone(), bookmarks);
+		let camera = spawn_camera(&mut app, Transform::from_xyz(0., 0., 4.));
+
+		press(&mut app, &[KeyCode::Digit1]);
+		app.update();
+
+		assert_eq!(
+			(
+				Some(&BookmarkTransition::new(bookmark(4.), bookmark(8.), 2.)),
+				false
+			),
+			(
+				app.world().entity(camera).get::<BookmarkTransition>(),
+				path.exists()
+			)
+		);
+	}
+
+	#[test]
+	fn recall_bookmark_from_default_target_without_target() {
+		let bookmarks = CameraBookmarks(BTreeMap::from([("1".to_owned(), bookmark(8.))]));
+		let mut app = setup(temp_path(), bookmarks);
+		let camera = app
+			.world_mut()
+			.spawn((Camera::default(), Transform::from_xyz(0., 0.5, 4.)))
+			.id();
+
+		press(&mut app, &[KeyCode::Digit1]);
+		app.update();
+
+		let camera = app.world().entity(camera);
+		assert_eq!(
+			(Vec3::new(0., 0.5, 0.), Some(&OrbitTarget::default())),
+			(
+				camera.get::<BookmarkTransition>().unwrap().from.anchor,
+				camera.get::<OrbitTarget>(),
+			)
+		);
+	}
+
+	#[test]
+	fn recall_nothing_for_unknown_bookmark() {
+		let mut app = setup(temp_path(), CameraBookmarks::default());
+		let camera = spawn_camera(&mut app, Transform::from_xyz(0., 0., 4.));
+
+		press(&mut app, &[KeyCode::Digit1]);
+		app.update();
+
+		assert_eq!(None, app.world().entity(camera).get::<BookmarkTransition>());
+	}
+
+	#[test]
+	fn recall_stops_other_camera_motion() {
+		let bookmarks = CameraBookmarks(BTreeMap::from([("1".to_owned(), bookmark(8.))]));
+		let mut app = setup(temp_path(), bookmarks);
+		let camera = spawn_camera(&mut app, Transform::from_xyz(0., 0., 4.));
+		app.world_mut().entity_mut(camera).insert((
+			SmoothZoom {
+				rate: 1.,
+				target_distance: Some(3.),
+			},
+			OrbitInertia {
+				damping: 1.,
+				velocity: Vec2::ONE,
+			},
+			FocusTransition::new(
+				crate::components::Focus {
+					anchor: Vec3::ZERO,
+					distance: 4.,
+				},
+				crate::components::Focus {
+					anchor: Vec3::ONE,
+					distance: 5.,
+				},
+				1.,
+			),
+		));
+
+		press(&mut app, &[KeyCode::Digit1]);
+		app.update();
+
+		let camera = app.world().entity(camera);
+		assert_eq!(
+			(None, Vec2::ZERO, false),
+			(
+				camera.get::<SmoothZoom>().unwrap().target_distance,
+				camera.get::<OrbitInertia>().unwrap().velocity,
+				camera.contains::<FocusTransition>(),
+			)
+		);
+	}
+}
@@ -1,35 +1,78 @@
-use crate::traits::movement::{Anchor, AnchoredMovement, Seconds};
+use crate::{
+	components::OrbitTarget,
+	traits::movement::{Anchor, AnchoredMovement, Orbit, Seconds},
+};
 use bevy::prelude::*;
 
+type Cameras<'w, 's, 'a, TFilter> = Query<
+	'w,
+	's,
+	(
+		Entity,
+		&'a mut Transform,
+		Option<&'a mut OrbitTarget>,
+		Option<&'a mut Orbit>,
+	),
+	(With<Camera>, TFilter),
+>;
+
 pub fn cam_movement<TEvent>(
+	mut commands: Commands,
 	time: Res<Time<Real>>,
 	extra: Res<TEvent::TExtra>,
-	mut cameras: Query<&mut Transform, With<Camera>>,
+	mut cameras: Cameras<TEvent::TFilter>,
+	transforms: Query<&GlobalTransform>,
 	mut events: EventReader<TEvent>,
 ) where
 	TEvent: AnchoredMovement + Event,
 	TEvent::TExtra: Resource + Copy,
 {
-	let around = Anchor(Vec3::new(0., 0.5, 0.));
 	let delta = Seconds(time.delta_seconds());
+	let events = events.read().collect::<Vec<_>>();
 
-	for event in events.read() {
-		apply_event_transformations(&mut cameras, event, around, delta, *extra);
+	if events.is_empty() {
+		return;
 	}
-}
 
-fn apply_event_transformations<TEvent>(
-	cameras: &mut Query<&mut Transform, With<Camera>>,
-	event: &TEvent,
-	around: Anchor,
-	delta: Seconds,
-	extra: TEvent::TExtra,
-) where
-	TEvent: AnchoredMovement,
-	TEvent::TExtra: Copy,
-{
-	for mut transform in cameras {
-		event.anchored_movement(transform.as_mut(), around, delta, extra);
+	for (entity, mut transform, target, orbit) in &mut cameras {
+		let mut moved_target = target.as_deref().copied().unwrap_or_default();
+		let mut moved_orbit = orbit.as_deref().copied();
+
+		for event in &events {
+			let Some(position) = moved_target.position(&transforms) else {
+				break;
+			};
+			let around = Anchor(position);
+			let mut current =
+				moved_orbit.unwrap_or_else(|| Orbit::from_transform(&transform, around));
+			current.sync(&transform, around);
+			let Anchor(moved) = event.moved_anchor(&transform, around, delta, *extra);
+			event.orbit_movement(transform.as_mut(), &mut current, around, delta, *extra);
+			current.sync(&transform, Anchor(moved));
+
+			moved_orbit = Some(current);
+			moved_target.offset += moved - position;
+		}
+
+		match (orbit, moved_orbit) {
+			(Some(mut orbit), Some(moved_orbit)) => {
+				orbit.set_if_neq(moved_orbit);
+			}
+			(None, Some(moved_orbit)) => {
+				commands.entity(entity).insert(moved_orbit);
+			}
+			_ => {}
+		}
+
+		match target {
+			Some(mut target) => {
+				target.set_if_neq(moved_target);
+			}
+			None if moved_target != OrbitTarget::default() => {
+				commands.entity(entity).insert(moved_target);
+			}
+			None => {}
+		}
 	}
 }
 
@@ -37,33 +80,50 @@ fn apply_event_transformations<TEvent>(
 mod tests {
 	use super::*;
 	use crate::{
+		assert_approx_eq,
+		components::OrbitTarget,
 		tools::test_tools::tick_time,
 		traits::movement::{Anchor, Seconds},
 	};
 	use bevy::ecs::system::RunSystemOnce;
 	use mockall::{automock, predicate::eq};
-	use std::time::Duration;
+	use std::{f32::consts::PI, time::Duration};
 
 	#[derive(Event)]
 	struct MyEvent {
 		mock: MockMyEvent,
+		anchor_shift: Vec3,
 	}
 
 	#[derive(Resource, Default, Debug, PartialEq, Clone, Copy)]
 	pub struct MyExtra;
 
+	#[derive(Component)]
+	pub struct MyExcluded;
+
 	impl MyEvent {
 		fn with_mock(mut setup: impl FnMut(&mut MockMyEvent)) -> Self {
 			let mut mock = MockMyEvent::default();
 			setup(&mut mock);
 
-			Self { mock }
+			Self {
+				mock,
+				anchor_shift: Vec3::ZERO,
+			}
+		}
+
+		fn moving_anchor_by(self, anchor_shift: Vec3) -> Self {
+			Self {
+				anchor_shift,
+				..self
+			}
 		}
 	}
 
 	#[automock]
 	impl AnchoredMovement for MyEvent {
 		type TExtra = MyExtra;
+		type TFilter = Without<MyExcluded>;
 
 		fn anchored_movement(
 			&self,
@@ -74,6 +134,17 @@ mod tests {
 		) {
 			self.mock.anchored_movement(agent, around, delta, extra);
 		}
+
+		fn moved_anchor(
+			&self,
+			_agent: &Transform,
+			around: Anchor,
+			_delta: Seconds,
+			_extra: Self::TExtra,
+		) -> Anchor {
+			let Anchor(around) = around;
+			Anchor(around + self.anchor_shift)
+		}
 	}
 
 	fn setup() -> App {
@@ -86,11 +157,15 @@ mod tests {
 		app
 	}
 
+	fn target() -> OrbitTarget {
+		OrbitTarget::point(Vec3::new(0., 0.5, 0.))
+	}
+
 	#[test]
 	fn apply_anchored_movement() {
 		let mut app = setup();
 		app.world_mut()
-			.spawn((Transform::from_xyz(1., 2., 3.), Camera::default()));
+			.spawn((Transform::from_xyz(1., 2., 3.), Camera::default(), target()));
 
 		tick_time(&mut app, Duration::from_secs(42));
 		app.world_mut().send_event(MyEvent::with_mock(assert));
@@ -112,7 +187,8 @@ mod tests {
 	#[test]
 	fn do_not_apply_anchored_movement_when_not_camera_present() {
 		let mut app = setup();
-		app.world_mut().spawn(Transform::from_xyz(1., 2., 3.));
+		app.world_mut()
+			.spawn((Transform::from_xyz(1., 2., 3.), target()));
 
 		tick_time(&mut app, Duration::from_secs(42));
 		app.world_mut().send_event(MyEvent::with_mock(assert));
@@ -127,9 +203,9 @@ mod tests {
 	fn apply_anchored_movement_for_multiple_cameras() {
 		let mut app = setup();
 		app.world_mut()
-			.spawn((Transform::from_xyz(1., 2., 3.), Camera::default()));
+			.spawn((Transform::from_xyz(1., 2., 3.), Camera::default(), target()));
 		app.world_mut()
-			.spawn((Transform::from_xyz(4., 5., 6.), Camera::default()));
+			.spawn((Transform::from_xyz(4., 5., 6.), Camera::default(), target()));
 
 		tick_time(&mut app, Duration::from_secs(11));
 		app.world_mut().send_event(MyEvent::with_mock(assert));
@@ -156,4 +232,315 @@ mod tests {
 				.return_const(());
 		}
 	}
+
+	#[test]
+	fn apply_anchored_movement_around_each_cameras_target() {
+		let mut app = setup();
+		app.world_mut().spawn((
+			Transform::from_xyz(1., 2., 3.),
+			Camera::default(),
+			OrbitTarget::point(Vec3::new(1., 0., 0.)),
+		));
+		app.world_mut().spawn((
+			Transform::from_xyz(4., 5., 6.),
+			Camera::default(),
+			OrbitTarget::point(Vec3::new(2., 0., 0.)).with_offset(Vec3::Y),
+		));
+
+		tick_time(&mut app, Duration::from_secs(11));
+		app.world_mut().send_event(MyEvent::with_mock(assert));
+		app.world_mut().run_system_once(cam_movement::<MyEvent>);
+
+		fn assert(mock: &mut MockMyEvent) {
+			mock.expect_anchored_movement()
+				.with(
+					eq(Transform::from_xyz(1., 2., 3.)),
+					eq(Anchor(Vec3::new(1., 0., 0.))),
+					eq(Seconds(11.)),
+					eq(MyExtra),
+				)
+				.times(1)
+				.return_const(());
+			mock.expect_anchored_movement()
+				.with(
+					eq(Transform::from_xyz(4., 5., 6.)),
+					eq(Anchor(Vec3::new(2., 1., 0.))),
+					eq(Seconds(11.)),
+					eq(MyExtra),
+				)
+				.times(1)
+				.return_const(());
+		}
+	}
+
+	#[test]
+	fn apply_anchored_movement_around_target_entity() {
+		let mut app = setup();
+		let model = app
+			.world_mut()
+			.spawn(GlobalTransform::from_xyz(4., 5., 6.))
+			.id();
+		app.world_mut().spawn((
+			Transform::from_xyz(1., 2., 3.),
+			Camera::default(),
+			OrbitTarget::entity(model).with_offset(Vec3::Y),
+		));
+
+		tick_time(&mut app, Duration::from_secs(42));
+		app.world_mut().send_event(MyEvent::with_mock(assert));
+		app.world_mut().run_system_once(cam_movement::<MyEvent>);
+
+		fn assert(mock: &mut MockMyEvent) {
+			mock.expect_anchored_movement()
+				.with(
+					eq(Transform::from_xyz(1., 2., 3.)),
+					eq(Anchor(Vec3::new(4., 6., 6.))),
+					eq(Seconds(42.)),
+					eq(MyExtra),
+				)
+				.times(1)
+				.return_const(());
+		}
+	}
+
+	#[test]
+	fn apply_anchored_movement_around_default_anchor_without_target() {
+		let mut app = setup();
+		app.world_mut()
+			.spawn((Transform::from_xyz(1., 2., 3.), Camera::default()));
+
+		tick_time(&mut app, Duration::from_secs(42));
+		app.world_mut().send_event(MyEvent::with_mock(assert));
+		app.world_mut().run_system_once(cam_movement::<MyEvent>);
+
+		fn assert(mock: &mut MockMyEvent) {
+			mock.expect_anchored_movement()
+				.with(
+					eq(Transform::from_xyz(1., 2., 3.)),
+					eq(Anchor(Vec3::new(0., 0.5, 0.))),
+					eq(Seconds(42.)),
+					eq(MyExtra),
+				)
+				.times(1)
+				.return_const(());
+		}
+	}
+
+	#[test]
+	fn insert_moved_default_target_without_target() {
+		let mut app = setup();
+		let camera = app
+			.world_mut()
+			.spawn((Transform::from_xyz(1., 2., 3.), Camera::default()))
+			.id();
+
+		app.world_mut()
+			.send_event(MyEvent::with_mock(assert).moving_anchor_by(Vec3::Y * 2.));
+		app.world_mut().run_system_once(cam_movement::<MyEvent>);
+
+		assert_eq!(
+			Some(&OrbitTarget::point(Vec3::new(0., 0.5, 0.)).with_offset(Vec3::new(0., 2., 0.))),
+			app.world().entity(camera).get::<OrbitTarget>()
+		);
+
+		fn assert(mock: &mut MockMyEvent) {
+			mock.expect_anchored_movement().return_const(());
+		}
+	}
+
+	#[test]
+	fn insert_default_target_moved_by_all_events_without_target() {
+		let mut app = setup();
+		let camera = app
+			.world_mut()
+			.spawn((Transform::from_xyz(1., 2., 3.), Camera::default()))
+			.id();
+
+		app.world_mut()
+			.send_event(MyEvent::with_mock(assert).moving_anchor_by(Vec3::X));
+		app.world_mut()
+			.send_event(MyEvent::with_mock(assert).moving_anchor_by(Vec3::Y * 2.));
+		app.world_mut().run_system_once(cam_movement::<MyEvent>);
+
+		assert_eq!(
+			Some(&OrbitTarget::point(Vec3::new(0., 0.5, 0.)).with_offset(Vec3::new(1., 2., 0.))),
+			app.world().entity(camera).get::<OrbitTarget>()
+		);
+
+		fn assert(mock: &mut MockMyEvent) {
+			mock.expect_anchored_movement().return_const(());
+		}
+	}
+
+	#[test]
+	fn move_around_anchor_moved_by_previous_event_without_target() {
+		let mut app = setup();
+		app.world_mut()
+			.spawn((Transform::from_xyz(1., 2., 3.), Camera::default()));
+
+		app.world_mut()
+			.send_event(MyEvent::with_mock(ignore).moving_anchor_by(Vec3::X));
+		app.world_mut().send_event(MyEvent::with_mock(assert));
+		app.world_mut().run_system_once(cam_movement::<MyEvent>);
+
+		fn assert(mock: &mut MockMyEvent) {
+			mock.expect_anchored_movement()
+				.with(
+					eq(Transform::from_xyz(1., 2., 3.)),
+					eq(Anchor(Vec3::new(1., 0.5, 0.))),
+					eq(Seconds(0.)),
+					eq(MyExtra),
+				)
+				.times(1)
+				.return_const(());
+		}
+
+		fn ignore(mock: &mut MockMyEvent) {
+			mock.expect_anchored_movement().return_const(());
+		}
+	}
+
+	#[test]
+	fn do_not_insert_target_when_default_anchor_not_moved() {
+		let mut app = setup();
+		let camera = app
+			.world_mut()
+			.spawn((Transform::from_xyz(1., 2., 3.), Camera::default()))
+			.id();
+
+		app.world_mut().send_event(MyEvent::with_mock(assert));
+		app.world_mut().run_system_once(cam_movement::<MyEvent>);
+
+		assert_eq!(None, app.world().entity(camera).get::<OrbitTarget>());
+
+		fn assert(mock: &mut MockMyEvent) {
+			mock.expect_anchored_movement().return_const(());
+		}
+	}
+
+	#[test]
+	fn do_not_apply_anchored_movement_when_target_entity_missing() {
+		let mut app = setup();
+		let model = app.world_mut().spawn_empty().id();
+		app.world_mut().spawn((
+			Transform::from_xyz(1., 2., 3.),
+			Camera::default(),
+			OrbitTarget::entity(model),
+		));
+
+		tick_time(&mut app, Duration::from_secs(42));
+		app.world_mut().send_event(MyEvent::with_mock(assert));
+		app.world_mut().run_system_once(cam_movement::<MyEvent>);
+
+		fn assert(mock: &mut MockMyEvent) {
+			mock.expect_anchored_movement().never().return_const(());
+		}
+	}
+
+	#[test]
+	fn do_not_apply_anchored_movement_to_filtered_cameras() {
+		let mut app = setup();
+		app.world_mut().spawn((
+			Transform::from_xyz(1., 2., 3.),
+			Camera::default(),
+			target(),
+			MyExcluded,
+		));
+
+		tick_time(&mut app, Duration::from_secs(42));
+		app.world_mut().send_event(MyEvent::with_mock(assert));
+		app.world_mut().run_system_once(cam_movement::<MyEvent>);
+
+		fn assert(mock: &mut MockMyEvent) {
+			mock.expect_anchored_movement().never().return_const(());
+		}
+	}
+
+	#[test]
+	fn insert_orbit_of_moved_camera() {
+		let mut app = setup();
+		let camera = app
+			.world_mut()
+			.spawn((
+				Transform::from_xyz(0., 0.5, 2.),
+				Camera::default(),
+				target(),
+			))
+			.id();
+
+		app.world_mut().send_event(MyEvent::with_mock(assert));
+		app.world_mut().run_system_once(cam_movement::<MyEvent>);
+
+		assert_eq!(
+			Some(&Orbit {
+				yaw: 0.,
+				pitch: 0.,
+				distance: 2.,
+			}),
+			app.world().entity(camera).get::<Orbit>()
+		);
+
+		fn assert(mock: &mut MockMyEvent) {
+			mock.expect_anchored_movement().return_const(());
+		}
+	}
+
+	#[test]
+	fn sync_orbit_turning_past_pi() {
+		let mut app = setup();
+		let camera = app
+			.world_mut()
+			.spawn((
+				Transform::from_translation(
+					Vec3::new(0., 0.5, 0.) + Quat::from_rotation_y(PI * 0.9) * Vec3::Z * 2.,
+				),
+				Camera::default(),
+				target(),
+				Orbit {
+					yaw: PI * 0.9,
+					pitch: 0.,
+					distance: 2.,
+				},
+			))
+			.id();
+
+		app.world_mut().send_event(MyEvent::with_mock(assert));
+		app.world_mut().run_system_once(cam_movement::<MyEvent>);
+
+		let orbit = app.world().entity(camera).get::<Orbit>().unwrap();
+		assert_approx_eq!(PI * 1.1, orbit.yaw, 0.0001);
+
+		fn assert(mock: &mut MockMyEvent) {
+			mock.expect_anchored_movement()
+				.returning(|agent, Anchor(anchor), _, _| {
+					agent.translation = anchor + Quat::from_rotation_y(PI * 1.1) * Vec3::Z * 2.;
+				});
+		}
+	}
+
+	#[test]
+	fn move_orbit_target_by_moved_anchor() {
+		let mut app = setup();
+		let camera = app
+			.world_mut()
+			.spawn((
+				Transform::from_xyz(1., 2., 3.),
+				Camera::default(),
+				OrbitTarget::point(Vec3::new(0., 0.5, 0.)).with_offset(Vec3::X),
+			))
+			.id();
+
+		app.world_mut()
+			.send_event(MyEvent::with_mock(assert).moving_anchor_by(Vec3::Y * 2.));
+		app.world_mut().run_system_once(cam_movement::<MyEvent>);
+
+		assert_eq!(
+			Some(&OrbitTarget::point(Vec3::new(0., 0.5, 0.)).with_offset(Vec3::new(1., 2., 0.))),
+			app.world().entity(camera).get::<OrbitTarget>()
+		);
+
+		fn assert(mock: &mut MockMyEvent) {
+			mock.expect_anchored_movement().return_const(());
+		}
+	}
 }
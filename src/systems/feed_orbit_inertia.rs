@@ -0,0 +1,83 @@
+use crate::{components::OrbitInertia, resources::CameraRotationSettings};
+use bevy::{input::mouse::MouseMotion, prelude::*};
+
+/// Sets the [`OrbitInertia`] velocity to the rotation speed of the current mouse motion.
+///
+/// Run it only while rotating, the velocity is kept and damped otherwise.
+pub fn feed_orbit_inertia(
+	settings: Res<CameraRotationSettings>,
+	mut inertias: Query<&mut OrbitInertia, With<Camera>>,
+	mut events: EventReader<MouseMotion>,
+) {
+	let velocity = events
+		.read()
+		.map(|motion| settings.orbit_velocity(motion))
+		.sum::<Vec2>();
+
+	for mut inertia in &mut inertias {
+		inertia.velocity = velocity;
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use bevy::app::App;
+
+	fn setup() -> App {
+		let mut app = App::new();
+		app.insert_resource(CameraRotationSettings {
+			sensitivity: 2.,
+			..default()
+		});
+		app.add_event::<MouseMotion>();
+		app.add_systems(Update, feed_orbit_inertia);
+
+		app
+	}
+
+	fn velocity_of(app: &App, camera: Entity) -> Option<Vec2> {
+		app.world()
+			.entity(camera)
+			.get::<OrbitInertia>()
+			.map(|i| i.velocity)
+	}
+
+	#[test]
+	fn set_velocity_from_mouse_motion() {
+		let mut app = setup();
+		let camera = app
+			.world_mut()
+			.spawn((Camera::default(), OrbitInertia::new(1.)))
+			.id();
+
+		app.world_mut().send_event(MouseMotion {
+			delta: Vec2::new(1., 2.),
+		});
+		app.world_mut().send_event(MouseMotion {
+			delta: Vec2::new(3., 4.),
+		});
+		app.update();
+
+		assert_eq!(Some(Vec2::new(-8., 12.)), velocity_of(&app, camera));
+	}
+
+	#[test]
+	fn stop_while_holding_still() {
+		let mut app = setup();
+		let camera = app
+			.world_mut()
+			.spawn((
+				Camera::default(),
+				OrbitInertia {
+					damping: 1.,
+					velocity: Vec2::new(1., 1.),
+				},
+			))
+			.id();
+
+		app.update();
+
+		assert_eq!(Some(Vec2::ZERO), velocity_of(&app, camera));
+	}
+}
@@ -0,0 +1,94 @@
+use crate::{components::OrbitInertia, events::OrbitMotion, resources::CameraRotationSettings};
+use bevy::{input::mouse::MouseMotion, prelude::*};
+
+/// Sets the [`OrbitInertia`] velocity to the rotation speed of the current
+/// [`OrbitMotion`], like [`feed_orbit_inertia`](super::feed_orbit_inertia::feed_orbit_inertia)
+/// does for mouse rotation.
+///
+/// The velocity is kept and damped in frames without [`OrbitMotion`].
+pub fn feed_orbit_motion_inertia(
+	settings: Res<CameraRotationSettings>,
+	mut inertias: Query<&mut OrbitInertia, With<Camera>>,
+	mut events: EventReader<OrbitMotion>,
+) {
+	let velocities = events
+		.read()
+		.map(|motion| {
+			settings.orbit_velocity(&MouseMotion {
+				delta: motion.delta,
+			})
+		})
+		.collect::<Vec<_>>();
+
+	if velocities.is_empty() {
+		return;
+	}
+
+	let velocity = velocities.into_iter().sum::<Vec2>();
+	for mut inertia in &mut inertias {
+		inertia.velocity = velocity;
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use bevy::app::App;
+
+	fn setup() -> App {
+		let mut app = App::new();
+		app.insert_resource(CameraRotationSettings {
+			sensitivity: 2.,
+			..default()
+		});
+		app.add_event::<OrbitMotion>();
+		app.add_systems(Update, feed_orbit_motion_inertia);
+
+		app
+	}
+
+	fn velocity_of(app: &App, camera: Entity) -> Option<Vec2> {
+		app.world()
+			.entity(camera)
+			.get::<OrbitInertia>()
+			.map(|i| i.velocity)
+	}
+
+	#[test]
+	fn set_velocity_from_orbit_motion() {
+		let mut app = setup();
+		let camera = app
+			.world_mut()
+			.spawn((Camera::default(), OrbitInertia::new(1.)))
+			.id();
+
+		app.world_mut().send_event(OrbitMotion {
+			delta: Vec2::new(1., 2.),
+		});
+		app.world_mut().send_event(OrbitMotion {
+			delta: Vec2::new(3., 4.),
+		});
+		app.update();
+
+		assert_eq!(Some(Vec2::new(-8., 12.)), velocity_of(&app, camera));
+	}
+
+	#[test]
+	fn keep_velocity_without_orbit_motion() {
+		let mut app = setup();
+		let camera = app
+			.world_mut()
+			.spawn((
+				Camera::default(),
+				OrbitInertia {
+					damping: 1.,
+					velocity: Vec2::new(1., 1.),
+				},
+			))
+			.id();
+
+		app.update();
+
+		assert_eq!(Some(Vec2::new(1., 1.)), velocity_of(&app, camera));
+	}
+}
@@ -0,0 +1,263 @@
+use crate::{
+	components::{BookmarkTransition, Focus, FocusTransition, OrbitTarget, SmoothZoom},
+	events::FocusOn,
+	resources::CameraFocusSettings,
+};
+use bevy::{prelude::*, render::primitives::Aabb};
+use std::iter::once;
+
+type Cameras<'a> = (
+	Entity,
+	&'a Transform,
+	Option<&'a OrbitTarget>,
+	&'a Projection,
+	Option<&'a mut SmoothZoom>,
+);
+
+/// Starts a [`FocusTransition`] of perspective cameras towards the combined bounds of the
+/// entity's meshes, for the last [`FocusOn`] event.
+///
+/// Cameras without an [`OrbitTarget`] get the default target inserted, which the
+/// transition moves.
+pub fn focus_on(
+	mut commands: Commands,
+	settings: Res<CameraFocusSettings>,
+	mut events: EventReader<FocusOn>,
+	mut cameras: Query<Cameras, With<Camera>>,
+	meshes: Query<(&Aabb, &GlobalTransform)>,
+	children: Query<&Children>,
+	transforms: Query<&GlobalTransform>,
+) {
+	let Some(&FocusOn(entity)) = events.read().last() else {
+		return;
+	};
+	let corners = once(entity)
+		.chain(children.iter_descendants(entity))
+		.filter_map(|entity| meshes.get(entity).ok())
+		.flat_map(|(aabb, transform)| world_corners(aabb, transform));
+	let Some(bounds) = Aabb::enclosing(corners) else {
+		return;
+	};
+
+	for (camera, transform, target, projection, smooth_zoom) in &mut cameras {
+		let Projection::Perspective(perspective) = projection else {
+			continue;
+		};
+		let orbit_target = target.copied().unwrap_or_default();
+		let Some(anchor) = orbit_target.position(&transforms) else {
+			continue;
+		};
+		let from = Focus {
+			anchor,
+			distance: (transform.translation - anchor).length(),
+		};
+		let to = Focus::framing(
+			&bounds,
+			perspective.fov,
+			perspective.aspect_ratio,
+			settings.padding,
+		);
+
+		if let Some(mut smooth_zoom) = smooth_zoom {
+			smooth_zoom.target_distance = None;
+		}
+		let mut camera = commands.entity(camera);
+		camera
+			.remove::<BookmarkTransition>()
+			.insert(FocusTransition::new(from, to, settings.duration));
+		if target.is_none() {
+			camera.insert(orbit_target);
+		}
+	}
+}
+
+fn world_corners(aabb: &Aabb, transform: &GlobalTransform) -> [Vec3; 8] {
+	let (min, max) = (Vec3::from(aabb.min()), Vec3::from(aabb.max()));
+
+	std::array::from_fn(|i| {
+		let corner = Vec3::select(BVec3::new(i & 1 != 0, i & 2 != 0, i & 4 != 0), max, min);
+		transform.transform_point(corner)
+	})
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::assert_approx_eq;
+	use std::f32::consts::PI;
+
+	const TOLERANCE: Vec3 = Vec3::splat(0.00001);
+
+	fn setup(settings: CameraFocusSettings) -> App {
+		let mut app = App::new();
+		app.insert_resource(settings);
+		app.add_event::<FocusOn>();
+		app.add_systems(Update, focus_on);
+
+		app
+	}
+
+	fn perspective(fov: f32) -> Projection {
+		Projection::Perspective(PerspectiveProjection {
+			fov,
+			aspect_ratio: 1.,
+			..default()
+		})
+	}
+
+	fn spawn_camera(app: &mut App, projection: Projection) -> Entity {
+		app.world_mut()
+			.spawn((
+				Camera::default(),
+				Transform::from_xyz(0., 0., 10.),
+				OrbitTarget::point(Vec3::ZERO),
+				projection,
+			))
+			.id()
+	}
+
+	fn spawn_mesh(app: &mut App, center: Vec3, half_extents: Vec3) -> Entity {
+		app.world_mut()
+			.spawn((
+				Aabb::from_min_max(-half_extents, half_extents),
+				GlobalTransform::from_translation(center),
+			))
+			.id()
+	}
+
+	#[test]
+	fn world_corners_transformed() {
+		let aabb = Aabb::from_min_max(Vec3::ZERO, Vec3::ONE);
+		let transform = GlobalTransform::from(
+			Transform::from_xyz(1., 2., 3.).with_scale(Vec3::new(2., 2., 2.)),
+		);
+
+		let corners = world_corners(&aabb, &transform);
+
+		assert_eq!(
+			Aabb::enclosing([Vec3::new(1., 2., 3.), Vec3::new(3., 4., 5.)]),
+			Aabb::enclosing(corners)
+		);
+	}
+
+	#[test]
+	fn world_corners_rotated() {
+		let aabb = Aabb::from_min_max(Vec3::new(0., 0., 0.), Vec3::new(2., 1., 1.));
+		let transform =
+			GlobalTransform::from(Transform::from_rotation(Quat::from_rotation_y(PI / 2.)));
+
+		let bounds = Aabb::enclosing(world_corners(&aabb, &transform)).unwrap();
+
+		assert_approx_eq!(Vec3::new(0., 0., -2.), Vec3::from(bounds.min()), TOLERANCE);
+		assert_approx_eq!(Vec3::new(1., 1., 0.), Vec3::from(bounds.max()), TOLERANCE);
+	}
+
+	#[test]
+	fn focus_combined_bounds_of_descendants() {
+		let mut app = setup(CameraFocusSettings {
+			duration: 2.,
+			padding: 1.,
+		});
+		let camera = spawn_camera(&mut app, perspective(PI / 2.));
+		let model = app.world_mut().spawn(GlobalTransform::default()).id();
+		let left = spawn_mesh(&mut app, Vec3::new(-1., 0., 0.), Vec3::splat(1.));
+		let right = spawn_mesh(&mut app, Vec3::new(3., 0., 0.), Vec3::splat(1.));
+		app.world_mut().entity_mut(model).push_children(&[left]);
+		app.world_mut().entity_mut(left).push_children(&[right]);
+
+		app.world_mut().send_event(FocusOn(model));
+		app.update();
+
+		let transition = app.world().entity(camera).get::<FocusTransition>().unwrap();
+		let radius = Vec3::new(3., 1., 1.).length();
+		assert_eq!(
+			(
+				Focus {
+					anchor: Vec3::ZERO,
+					distance: 10.
+				},
+				Vec3::new(1., 0., 0.),
+				2.,
+			),
+			(transition.from, transition.to.anchor, transition.duration)
+		);
+		assert_approx_eq!(radius / (PI / 4.).sin(), transition.to.distance, 0.00001);
+	}
+
+	#[test]
+	fn focus_entity_own_mesh() {
+		let mut app = setup(CameraFocusSettings::default());
+		let camera = spawn_camera(&mut app, perspective(PI / 2.));
+		let model = spawn_mesh(&mut app, Vec3::new(1., 2., 3.), Vec3::splat(1.));
+
+		app.world_mut().send_event(FocusOn(model));
+		app.update();
+
+		let transition = app.world().entity(camera).get::<FocusTransition>().unwrap();
+		assert_eq!(Vec3::new(1., 2., 3.), transition.to.anchor);
+	}
+
+	#[test]
+	fn focus_from_default_target_without_target() {
+		let mut app = setup(CameraFocusSettings::default());
+		let camera = spawn_camera(&mut app, perspective(PI / 2.));
+		app.world_mut().entity_mut(camera).remove::<OrbitTarget>();
+		let model = spawn_mesh(&mut app, Vec3::ZERO, Vec3::splat(1.));
+
+		app.world_mut().send_event(FocusOn(model));
+		app.update();
+
+		let camera = app.world().entity(camera);
+		assert_eq!(
+			(Vec3::new(0., 0.5, 0.), Some(&OrbitTarget::default())),
+			(
+				camera.get::<FocusTransition>().unwrap().from.anchor,
+				camera.get::<OrbitTarget>(),
+			)
+		);
+	}
+
+	#[test]
+	fn do_not_focus_entity_without_meshes() {
+		let mut app = setup(CameraFocusSettings::default());
+		let camera = spawn_camera(&mut app, perspective(PI / 2.));
+		let model = app.world_mut().spawn(GlobalTransform::default()).id();
+
+		app.world_mut().send_event(FocusOn(model));
+		app.update();
+
+		assert_eq!(None, app.world().entity(camera).get::<FocusTransition>());
+	}
+
+	#[test]
+	fn do_not_focus_with_orthographic_camera() {
+		let mut app = setup(CameraFocusSettings::default());
+		let camera = spawn_camera(
+			&mut app,
+			Projection::Orthographic(OrthographicProjection::default()),
+		);
+		let model = spawn_mesh(&mut app, Vec3::ZERO, Vec3::splat(1.));
+
+		app.world_mut().send_event(FocusOn(model));
+		app.update();
+
+		assert_eq!(None, app.world().entity(camera).get::<FocusTransition>());
+	}
+
+	#[test]
+	fn cancel_smooth_zoom() {
+		let mut app = setup(CameraFocusSettings::default());
+		let camera = spawn_camera(&mut app, perspective(PI / 2.));
+		app.world_mut().entity_mut(camera).insert(SmoothZoom {
+			rate: 1.,
+			target_distance: Some(4.),
+		});
+		let model = spawn_mesh(&mut app, Vec3::ZERO, Vec3::splat(1.));
+
+		app.world_mut().send_event(FocusOn(model));
+		app.update();
+
+		let zoom = app.world().entity(camera).get::<SmoothZoom>().unwrap();
+		assert_eq!(None, zoom.target_distance);
+	}
+}
@@ -0,0 +1,185 @@
+use crate::components::OrbitTarget;
+use bevy::{ecs::entity::EntityHashMap, prelude::*};
+
+/// Moves cameras along with their [`OrbitTarget`], keeping their view on it.
+pub fn follow_orbit_target(
+	mut cameras: Query<(Entity, &mut Transform, Ref<OrbitTarget>), With<Camera>>,
+	transforms: Query<&GlobalTransform>,
+	mut last_positions: Local<EntityHashMap<Vec3>>,
+) {
+	last_positions.retain(|entity, _| cameras.contains(*entity));
+
+	for (entity, mut transform, target) in &mut cameras {
+		let Some(position) = target.position(&transforms) else {
+			last_positions.remove(&entity);
+			continue;
+		};
+		let last_position = last_positions.insert(entity, position);
+
+		if target.is_changed() {
+			continue;
+		}
+		let Some(last_position) = last_position else {
+			continue;
+		};
+		if last_position == position {
+			continue;
+		}
+
+		transform.translation += position - last_position;
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use bevy::app::App;
+
+	fn setup() -> App {
+		let mut app = App::new();
+		app.add_systems(Update, follow_orbit_target);
+
+		app
+	}
+
+	#[test]
+	fn follow_moving_target_entity() {
+		let mut app = setup();
+		let model = app
+			.world_mut()
+			.spawn(GlobalTransform::from_xyz(1., 0., 0.))
+			.id();
+		let camera = app
+			.world_mut()
+			.spawn((
+				Transform::from_xyz(1., 2., 3.),
+				Camera::default(),
+				OrbitTarget::entity(model),
+			))
+			.id();
+
+		app.update();
+		app.world_mut()
+			.entity_mut(model)
+			.insert(GlobalTransform::from_xyz(2., 1., 0.));
+		app.update();
+
+		assert_eq!(
+			Some(&Transform::from_xyz(2., 3., 3.)),
+			app.world().entity(camera).get::<Transform>()
+		);
+	}
+
+	#[test]
+	fn do_not_move_camera_when_target_not_moved() {
+		let mut app = setup();
+		let model = app
+			.world_mut()
+			.spawn(GlobalTransform::from_xyz(1., 0., 0.))
+			.id();
+		let camera = app
+			.world_mut()
+			.spawn((
+				Transform::from_xyz(1., 2., 3.),
+				Camera::default(),
+				OrbitTarget::entity(model),
+			))
+			.id();
+
+		app.update();
+		app.update();
+
+		assert_eq!(
+			Some(&Transform::from_xyz(1., 2., 3.)),
+			app.world().entity(camera).get::<Transform>()
+		);
+	}
+
+	#[test]
+	fn do_not_move_camera_when_target_changed() {
+		let mut app = setup();
+		let camera = app
+			.world_mut()
+			.spawn((
+				Transform::from_xyz(1., 2., 3.),
+				Camera::default(),
+				OrbitTarget::point(Vec3::ZERO),
+			))
+			.id();
+
+		app.update();
+		app.world_mut()
+			.entity_mut(camera)
+			.insert(OrbitTarget::point(Vec3::new(4., 5., 6.)));
+		app.update();
+
+		assert_eq!(
+			Some(&Transform::from_xyz(1., 2., 3.)),
+			app.world().entity(camera).get::<Transform>()
+		);
+	}
+
+	#[test]
+	fn follow_target_after_target_changed() {
+		let mut app = setup();
+		let model = app
+			.world_mut()
+			.spawn(GlobalTransform::from_xyz(1., 0., 0.))
+			.id();
+		let camera = app
+			.world_mut()
+			.spawn((
+				Transform::from_xyz(1., 2., 3.),
+				Camera::default(),
+				OrbitTarget::point(Vec3::ZERO),
+			))
+			.id();
+
+		app.update();
+		app.world_mut()
+			.entity_mut(camera)
+			.insert(OrbitTarget::entity(model));
+		app.update();
+		app.world_mut()
+			.entity_mut(model)
+			.insert(GlobalTransform::from_xyz(1., 1., 0.));
+		app.update();
+
+		assert_eq!(
+			Some(&Transform::from_xyz(1., 3., 3.)),
+			app.world().entity(camera).get::<Transform>()
+		);
+	}
+
+	#[test]
+	fn do_not_move_camera_when_target_entity_missing() {
+		let mut app = setup();
+		let model = app
+			.world_mut()
+			.spawn(GlobalTransform::from_xyz(1., 0., 0.))
+			.id();
+		let camera = app
+			.world_mut()
+			.spawn((
+				Transform::from_xyz(1., 2., 3.),
+				Camera::default(),
+				OrbitTarget::entity(model),
+			))
+			.id();
+
+		app.update();
+		app.world_mut()
+			.entity_mut(model)
+			.remove::<GlobalTransform>();
+		app.update();
+		app.world_mut()
+			.entity_mut(model)
+			.insert(GlobalTransform::from_xyz(5., 0., 0.));
+		app.update();
+
+		assert_eq!(
+			Some(&Transform::from_xyz(1., 2., 3.)),
+			app.world().entity(camera).get::<Transform>()
+		);
+	}
+}
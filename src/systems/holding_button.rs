@@ -1,5 +1,8 @@
 use bevy::{input::ButtonInput, prelude::*};
-use std::hash::Hash;
+use std::{hash::Hash, time::Duration};
+
+/// Elapsed real time of a condition's last relevant event
+type Since<'s> = Local<'s, Option<Duration>>;
 
 pub fn holding_button<TButton>(button: TButton) -> impl Fn(Res<ButtonInput<TButton>>) -> bool
 where
@@ -8,24 +11,144 @@ where
 	move |input: Res<ButtonInput<TButton>>| input.pressed(button)
 }
 
+/// True while all `buttons` are held, which is also the case for no `buttons`.
+pub fn holding_all<TButton>(
+	buttons: impl IntoIterator<Item = TButton>,
+) -> impl Fn(Res<ButtonInput<TButton>>) -> bool
+where
+	TButton: Copy + Eq + Hash + Sync + Send + 'static,
+{
+	let buttons = buttons.into_iter().collect::<Vec<_>>();
+	move |input: Res<ButtonInput<TButton>>| input.all_pressed(buttons.iter().copied())
+}
+
+/// True while any of the `buttons` is held.
+pub fn holding_any<TButton>(
+	buttons: impl IntoIterator<Item = TButton>,
+) -> impl Fn(Res<ButtonInput<TButton>>) -> bool
+where
+	TButton: Copy + Eq + Hash + Sync + Send + 'static,
+{
+	let buttons = buttons.into_iter().collect::<Vec<_>>();
+	move |input: Res<ButtonInput<TButton>>| input.any_pressed(buttons.iter().copied())
+}
+
+/// True in the frame `button` is pressed while `modifier` is held.
+///
+/// The modifier may be of another input type, like [`KeyCode::ControlLeft`] for a
+/// [`MouseButton`].
+pub fn pressed_with_modifier<TButton, TModifier>(
+	button: TButton,
+	modifier: TModifier,
+) -> impl Fn(Res<ButtonInput<TButton>>, Res<ButtonInput<TModifier>>) -> bool
+where
+	TButton: Copy + Eq + Hash + Sync + Send + 'static,
+	TModifier: Copy + Eq + Hash + Sync + Send + 'static,
+{
+	move |input: Res<ButtonInput<TButton>>, modifiers: Res<ButtonInput<TModifier>>| {
+		input.just_pressed(button) && modifiers.pressed(modifier)
+	}
+}
+
+/// True in the frame `button` is pressed the second time within `max_interval`.
+///
+/// A third press starts a new double click. Presses are only noticed in frames the
+/// condition is evaluated, so avoid short circuiting it behind other conditions.
+pub fn double_clicked<TButton>(
+	button: TButton,
+	max_interval: Duration,
+) -> impl FnMut(Res<ButtonInput<TButton>>, Res<Time<Real>>, Since) -> bool
+where
+	TButton: Copy + Eq + Hash + Sync + Send + 'static,
+{
+	move |input: Res<ButtonInput<TButton>>, time: Res<Time<Real>>, mut last_press: Since| {
+		if !input.just_pressed(button) {
+			return false;
+		}
+
+		let now = time.elapsed();
+		match last_press.replace(now) {
+			Some(last) if now - last <= max_interval => {
+				*last_press = None;
+				true
+			}
+			_ => false,
+		}
+	}
+}
+
+/// True while `button` has been held for at least `duration`.
+///
+/// Holding is timed from the first frame the condition sees the button held, so
+/// avoid short circuiting it behind other conditions.
+pub fn holding_for<TButton>(
+	button: TButton,
+	duration: Duration,
+) -> impl FnMut(Res<ButtonInput<TButton>>, Res<Time<Real>>, Since) -> bool
+where
+	TButton: Copy + Eq + Hash + Sync + Send + 'static,
+{
+	move |input: Res<ButtonInput<TButton>>, time: Res<Time<Real>>, mut held_since: Since| {
+		if !input.pressed(button) {
+			*held_since = None;
+			return false;
+		}
+
+		let now = time.elapsed();
+		if input.just_pressed(button) {
+			*held_since = Some(now);
+		}
+		let held_since = held_since.get_or_insert(now);
+		now - *held_since >= duration
+	}
+}
+
 #[cfg(test)]
 mod tests {
 	use super::*;
+	use crate::tools::test_tools::tick_time;
 	use bevy::ecs::system::RunSystemOnce;
 
 	#[derive(Clone, Copy, PartialEq, Eq, Hash)]
 	enum MyButton {
 		Left,
 		Right,
+		Middle,
+	}
+
+	#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+	enum MyModifier {
+		Shift,
 	}
 
 	fn setup() -> App {
 		let mut app = App::new();
 		app.init_resource::<ButtonInput<MyButton>>();
+		app.init_resource::<ButtonInput<MyModifier>>();
+		app.init_resource::<Time<Real>>();
 
 		app
 	}
 
+	fn press(app: &mut App, button: MyButton) {
+		app.world_mut()
+			.resource_mut::<ButtonInput<MyButton>>()
+			.press(button);
+	}
+
+	fn release(app: &mut App, button: MyButton) {
+		app.world_mut()
+			.resource_mut::<ButtonInput<MyButton>>()
+			.release(button);
+	}
+
+	fn next_frame(app: &mut App, delta: Duration) {
+		app.world_mut()
+			.resource_mut::<ButtonInput<MyButton>>()
+			.clear();
+		tick_time(app, delta);
+	}
+
 	#[test]
 	fn holding_button_true() {
 		let mut app = setup();
@@ -53,4 +176,203 @@ mod tests {
 
 		assert!(!is_holding);
 	}
+
+	#[test]
+	fn holding_all_true() {
+		let mut app = setup();
+		press(&mut app, MyButton::Left);
+		press(&mut app, MyButton::Right);
+
+		let is_holding = app
+			.world_mut()
+			.run_system_once(holding_all([MyButton::Left, MyButton::Right]));
+
+		assert!(is_holding);
+	}
+
+	#[test]
+	fn holding_all_false() {
+		let mut app = setup();
+		press(&mut app, MyButton::Left);
+
+		let is_holding = app
+			.world_mut()
+			.run_system_once(holding_all([MyButton::Left, MyButton::Right]));
+
+		assert!(!is_holding);
+	}
+
+	#[test]
+	fn holding_any_true() {
+		let mut app = setup();
+		press(&mut app, MyButton::Right);
+
+		let is_holding = app
+			.world_mut()
+			.run_system_once(holding_any([MyButton::Left, MyButton::Right]));
+
+		assert!(is_holding);
+	}
+
+	#[test]
+	fn holding_any_false() {
+		let mut app = setup();
+		press(&mut app, MyButton::Middle);
+
+		let is_holding = app
+			.world_mut()
+			.run_system_once(holding_any([MyButton::Left, MyButton::Right]));
+
+		assert!(!is_holding);
+	}
+
+	#[test]
+	fn pressed_with_modifier_true() {
+		let mut app = setup();
+		app.world_mut()
+			.resource_mut::<ButtonInput<MyModifier>>()
+			.press(MyModifier::Shift);
+		press(&mut app, MyButton::Left);
+
+		let pressed = app
+			.world_mut()
+			.run_system_once(pressed_with_modifier(MyButton::Left, MyModifier::Shift));
+
+		assert!(pressed);
+	}
+
+	#[test]
+	fn pressed_with_modifier_false_without_modifier() {
+		let mut app = setup();
+		press(&mut app, MyButton::Left);
+
+		let pressed = app
+			.world_mut()
+			.run_system_once(pressed_with_modifier(MyButton::Left, MyModifier::Shift));
+
+		assert!(!pressed);
+	}
+
+	#[test]
+	fn pressed_with_modifier_false_when_only_held() {
+		let mut app = setup();
+		app.world_mut()
+			.resource_mut::<ButtonInput<MyModifier>>()
+			.press(MyModifier::Shift);
+		press(&mut app, MyButton::Left);
+		next_frame(&mut app, Duration::ZERO);
+
+		let pressed = app
+			.world_mut()
+			.run_system_once(pressed_with_modifier(MyButton::Left, MyModifier::Shift));
+
+		assert!(!pressed);
+	}
+
+	#[test]
+	fn pressed_with_modifier_of_same_input_type() {
+		let mut app = setup();
+		press(&mut app, MyButton::Right);
+		press(&mut app, MyButton::Left);
+
+		let pressed = app
+			.world_mut()
+			.run_system_once(pressed_with_modifier(MyButton::Left, MyButton::Right));
+
+		assert!(pressed);
+	}
+
+	#[test]
+	fn double_clicked_true() {
+		let mut app = setup();
+		let double_clicked = app
+			.world_mut()
+			.register_system(double_clicked(MyButton::Left, Duration::from_millis(300)));
+
+		press(&mut app, MyButton::Left);
+		let first = app.world_mut().run_system(double_clicked).unwrap();
+		next_frame(&mut app, Duration::from_millis(100));
+		release(&mut app, MyButton::Left);
+		app.world_mut().run_system(double_clicked).unwrap();
+		next_frame(&mut app, Duration::from_millis(100));
+		press(&mut app, MyButton::Left);
+		let second = app.world_mut().run_system(double_clicked).unwrap();
+
+		assert_eq!((false, true), (first, second));
+	}
+
+	#[test]
+	fn double_clicked_false_when_too_slow() {
+		let mut app = setup();
+		let double_clicked = app
+			.world_mut()
+			.register_system(double_clicked(MyButton::Left, Duration::from_millis(300)));
+
+		press(&mut app, MyButton::Left);
+		app.world_mut().run_system(double_clicked).unwrap();
+		next_frame(&mut app, Duration::from_millis(100));
+		release(&mut app, MyButton::Left);
+		next_frame(&mut app, Duration::from_millis(250));
+		press(&mut app, MyButton::Left);
+		let second = app.world_mut().run_system(double_clicked).unwrap();
+
+		assert!(!second);
+	}
+
+	#[test]
+	fn double_clicked_starts_over_after_double_click() {
+		let mut app = setup();
+		let double_clicked = app
+			.world_mut()
+			.register_system(double_clicked(MyButton::Left, Duration::from_millis(300)));
+
+		let mut clicks = vec![];
+		for _ in 0..3 {
+			press(&mut app, MyButton::Left);
+			clicks.push(app.world_mut().run_system(double_clicked).unwrap());
+			next_frame(&mut app, Duration::from_millis(50));
+			release(&mut app, MyButton::Left);
+			next_frame(&mut app, Duration::from_millis(50));
+		}
+
+		assert_eq!(vec![false, true, false], clicks);
+	}
+
+	#[test]
+	fn holding_for_true() {
+		let mut app = setup();
+		let holding_for = app
+			.world_mut()
+			.register_system(holding_for(MyButton::Left, Duration::from_millis(500)));
+
+		press(&mut app, MyButton::Left);
+		let early = app.world_mut().run_system(holding_for).unwrap();
+		next_frame(&mut app, Duration::from_millis(499));
+		let almost = app.world_mut().run_system(holding_for).unwrap();
+		next_frame(&mut app, Duration::from_millis(1));
+		let long_enough = app.world_mut().run_system(holding_for).unwrap();
+
+		assert_eq!((false, false, true), (early, almost, long_enough));
+	}
+
+	#[test]
+	fn holding_for_restarts_after_release() {
+		let mut app = setup();
+		let holding_for = app
+			.world_mut()
+			.register_system(holding_for(MyButton::Left, Duration::from_millis(500)));
+
+		press(&mut app, MyButton::Left);
+		app.world_mut().run_system(holding_for).unwrap();
+		next_frame(&mut app, Duration::from_millis(400));
+		release(&mut app, MyButton::Left);
+		app.world_mut().run_system(holding_for).unwrap();
+		next_frame(&mut app, Duration::from_millis(100));
+		press(&mut app, MyButton::Left);
+		app.world_mut().run_system(holding_for).unwrap();
+		next_frame(&mut app, Duration::from_millis(400));
+		let held = app.world_mut().run_system(holding_for).unwrap();
+
+		assert!(!held);
+	}
 }
@@ -0,0 +1,90 @@
+use crate::resources::{CameraBookmarkSettings, CameraBookmarks};
+use bevy::prelude::*;
+use std::{fs, io::ErrorKind};
+
+/// Loads the [`CameraBookmarks`] from the RON file of the [`CameraBookmarkSettings`],
+/// keeping the current bookmarks when there is no such file.
+pub fn load_camera_bookmarks(
+	settings: Res<CameraBookmarkSettings>,
+	mut bookmarks: ResMut<CameraBookmarks>,
+) {
+	let ron = match fs::read_to_string(&settings.path) {
+		Ok(ron) => ron,
+		Err(error) if error.kind() == ErrorKind::NotFound => return,
+		Err(error) => {
+			error!("Cannot read camera bookmarks {:?}: {error}", settings.path);
+			return;
+		}
+	};
+
+	match CameraBookmarks::from_ron(&ron) {
+		Ok(loaded) => *bookmarks = loaded,
+		Err(error) => error!("Cannot parse camera bookmarks {:?}: {error}", settings.path),
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::components::CameraBookmark;
+	use bevy::ecs::system::RunSystemOnce;
+	use std::{collections::BTreeMap, path::PathBuf};
+	use uuid::Uuid;
+
+	fn temp_path() -> PathBuf {
+		std::env::temp_dir().join(format!("camera_bookmarks_{}.ron", Uuid::new_v4()))
+	}
+
+	fn setup(path: PathBuf, bookmarks: CameraBookmarks) -> App {
+		let mut app = App::new();
+		app.insert_resource(CameraBookmarkSettings { path, ..default() });
+		app.insert_resource(bookmarks);
+
+		app
+	}
+
+	fn bookmarks(distance: f32) -> CameraBookmarks {
+		CameraBookmarks(BTreeMap::from([(
+			"1".to_owned(),
+			CameraBookmark {
+				translation: Vec3::new(0., 0., distance),
+				rotation: Quat::IDENTITY,
+				anchor: Vec3::ZERO,
+				distance,
+			},
+		)]))
+	}
+
+	#[test]
+	fn load_bookmarks_from_file() {
+		let path = temp_path();
+		fs::write(&path, bookmarks(4.).to_ron().unwrap()).unwrap();
+		let mut app = setup(path.clone(), CameraBookmarks::default());
+
+		app.world_mut().run_system_once(load_camera_bookmarks);
+		fs::remove_file(path).unwrap();
+
+		assert_eq!(&bookmarks(4.), app.world().resource::<CameraBookmarks>());
+	}
+
+	#[test]
+	fn keep_bookmarks_without_file() {
+		let mut app = setup(temp_path(), bookmarks(4.));
+
+		app.world_mut().run_system_once(load_camera_bookmarks);
+
+		assert_eq!(&bookmarks(4.), app.world().resource::<CameraBookmarks>());
+	}
+
+	#[test]
+	fn keep_bookmarks_with_invalid_file() {
+		let path = temp_path();
+		fs::write(&path, "not ron").unwrap();
+		let mut app = setup(path.clone(), bookmarks(4.));
+
+		app.world_mut().run_system_once(load_camera_bookmarks);
+		fs::remove_file(path).unwrap();
+
+		assert_eq!(&bookmarks(4.), app.world().resource::<CameraBookmarks>());
+	}
+}
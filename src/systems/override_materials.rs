@@ -0,0 +1,546 @@
+use crate::{
+	components::{MaterialInstance, MaterialOverrides},
+	material::{material_time::MaterialTime, CustomMaterial},
+	resources::ShaderTime,
+};
+use bevy::{ecs::entity::EntityHashSet, prelude::*, utils::HashSet};
+use std::iter::once;
+
+type Instances<'a> = (
+	Entity,
+	&'a mut Handle<CustomMaterial>,
+	Option<&'a mut MaterialInstance>,
+);
+
+/// Swaps shared materials for per-entity instances below [`MaterialOverrides`] and keeps
+/// those instances in sync with their base materials and overrides.
+///
+/// Only instances of modified bases are re-derived, and only written when they differ.
+#[allow(clippy::too_many_arguments)]
+pub fn override_materials(
+	mut commands: Commands,
+	mut materials: ResMut<Assets<CustomMaterial>>,
+	shader_time: Res<ShaderTime>,
+	mut material_events: EventReader<AssetEvent<CustomMaterial>>,
+	changed_overrides: Query<Entity, Changed<MaterialOverrides>>,
+	mut removed_overrides: RemovedComponents<MaterialOverrides>,
+	mut instances: Query<Instances>,
+	overrides: Query<&MaterialOverrides>,
+	parents: Query<&Parent>,
+	children: Query<&Children>,
+) {
+	let modified_bases = material_events
+		.read()
+		.filter_map(|event| match event {
+			AssetEvent::Modified { id } => Some(*id),
+			_ => None,
+		})
+		.collect::<HashSet<_>>();
+	let mut changed = EntityHashSet::default();
+
+	for entity in changed_overrides.iter().chain(removed_overrides.read()) {
+		changed.insert(entity);
+		changed.extend(children.iter_descendants(entity));
+	}
+	changed.extend(
+		instances
+			.iter_mut()
+			.filter(|(_, handle, instance)| {
+				handle.is_added()
+					|| instance
+						.as_ref()
+						.is_some_and(|i| modified_bases.contains(&i.base.id()))
+			})
+			.map(|(entity, ..)| entity),
+	);
+
+	for entity in changed {
+		let Ok((_, mut handle, instance)) = instances.get_mut(entity) else {
+			continue;
+		};
+		let is_new = handle.is_added();
+		let instance = instance.filter(|_| !is_new);
+		let base = match &instance {
+			Some(instance) => instance.base.clone(),
+			None => handle.clone(),
+		};
+		let get_overrides = |entity| overrides.get(entity).ok();
+		let entity_overrides = once(entity)
+			.chain(parents.iter_ancestors(entity))
+			.find_map(get_overrides);
+
+		let Some(entity_overrides) = entity_overrides else {
+			if instance.is_some() {
+				*handle = base;
+				commands.entity(entity).remove::<MaterialInstance>();
+			}
+			continue;
+		};
+		let Some(base_material) = materials.get(&base) else {
+			continue;
+		};
+		let paused_secs = entity_overrides.paused.then(|| {
+			let paused_secs = instance.as_ref().and_then(|i| i.paused_secs);
+			paused_secs.unwrap_or(
+				shader_time.elapsed_secs * entity_overrides.time_scale
+					+ entity_overrides.time_offset,
+			)
+		});
+		let material = instantiate(base_material, entity_overrides, paused_secs);
+
+		match instance {
+			Some(mut instance) => {
+				instance.overrides = *entity_overrides;
+				instance.paused_secs = paused_secs;
+				if materials.get(handle.id()) != Some(&material) {
+					materials.insert(handle.id(), material);
+				}
+			}
+			None => {
+				*handle = materials.add(material);
+				commands.entity(entity).insert(MaterialInstance {
+					base,
+					overrides: *entity_overrides,
+					paused_secs,
+				});
+			}
+		}
+	}
+}
+
+fn instantiate(
+	base: &CustomMaterial,
+	overrides: &MaterialOverrides,
+	paused_secs: Option<f32>,
+) -> CustomMaterial {
+	let time = match paused_secs {
+		Some(paused_secs) => MaterialTime {
+			scale: 0.,
+			offset: paused_secs,
+		},
+		None => MaterialTime {
+			scale: overrides.time_scale,
+			offset: overrides.time_offset,
+		},
+	};
+
+	CustomMaterial {
+		color: LinearRgba::from_vec4(base.color.to_vec4() * overrides.tint.to_vec4()),
+		time: MaterialTime {
+			scale: time.scale * base.time.scale,
+			offset: time.offset * base.time.scale + base.time.offset,
+		},
+		..base.clone()
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use bevy::app::App;
+
+	fn setup() -> App {
+		let mut app = App::new();
+		app.init_resource::<Assets<CustomMaterial>>();
+		app.init_resource::<ShaderTime>();
+		app.add_event::<AssetEvent<CustomMaterial>>();
+		app.add_systems(Update, override_materials);
+
+		app
+	}
+
+	fn add_material(app: &mut App, material: CustomMaterial) -> Handle<CustomMaterial> {
+		app.world_mut()
+			.resource_mut::<Assets<CustomMaterial>>()
+			.add(material)
+	}
+
+	fn material_of(app: &App, entity: Entity) -> Option<&CustomMaterial> {
+		let handle = app.world().entity(entity).get::<Handle<CustomMaterial>>()?;
+		app.world().resource::<Assets<CustomMaterial>>().get(handle)
+	}
+
+	#[test]
+	fn create_instance_for_overridden_entity() {
+		let mut app = setup();
+		let base = add_material(&mut app, CustomMaterial::default());
+		let entity = app
+			.world_mut()
+			.spawn((base.clone(), MaterialOverrides::default()))
+			.id();
+
+		app.update();
+
+		let entity = app.world().entity(entity);
+		let handle = entity.get::<Handle<CustomMaterial>>();
+		let instance = entity.get::<MaterialInstance>();
+		assert_eq!(
+			(true, Some(&base)),
+			(handle != Some(&base), instance.map(|i| &i.base))
+		);
+	}
+
+	#[test]
+	fn create_instance_for_descendant_of_overridden_entity() {
+		let mut app = setup();
+		let base = add_material(&mut app, CustomMaterial::default());
+		let parent = app.world_mut().spawn(MaterialOverrides::default()).id();
+		let child = app.world_mut().spawn_empty().set_parent(parent).id();
+		let child_child = app.world_mut().spawn(base.clone()).set_parent(child).id();
+
+		app.update();
+
+		let child_child = app.world().entity(child_child);
+		assert_eq!(
+			Some(&base),
+			child_child.get::<MaterialInstance>().map(|i| &i.base)
+		);
+	}
+
+	#[test]
+	fn do_not_create_instance_without_overrides() {
+		let mut app = setup();
+		let base = add_material(&mut app, CustomMaterial::default());
+		let entity = app.world_mut().spawn(base.clone()).id();
+
+		app.update();
+
+		let entity = app.world().entity(entity);
+		assert_eq!(
+			(Some(&base), None),
+			(
+				entity.get::<Handle<CustomMaterial>>(),
+				entity.get::<MaterialInstance>()
+			)
+		);
+	}
+
+	#[test]
+	fn apply_tint() {
+		let mut app = setup();
+		let base = add_material(
+			&mut app,
+			CustomMaterial {
+				color: LinearRgba::new(0.5, 1., 1., 1.),
+				..default()
+			},
+		);
+		let entity = app
+			.world_mut()
+			.spawn((
+				base.clone(),
+				MaterialOverrides {
+					tint: LinearRgba::new(1., 0.5, 0.25, 1.),
+					..default()
+				},
+			))
+			.id();
+
+		app.update();
+
+		assert_eq!(
+			Some(LinearRgba::new(0.5, 0.5, 0.25, 1.)),
+			material_of(&app, entity).map(|m| m.color)
+		);
+	}
+
+	#[test]
+	fn separate_instances_per_entity() {
+		let mut app = setup();
+		let base = add_material(&mut app, CustomMaterial::default());
+		let a = app
+			.world_mut()
+			.spawn((base.clone(), MaterialOverrides::default()))
+			.id();
+		let b = app
+			.world_mut()
+			.spawn((base.clone(), MaterialOverrides::default()))
+			.id();
+
+		app.update();
+
+		let a = app.world().entity(a).get::<Handle<CustomMaterial>>();
+		let b = app.world().entity(b).get::<Handle<CustomMaterial>>();
+		assert_ne!(a, b);
+	}
+
+	#[test]
+	fn update_instance_when_overrides_change() {
+		let mut app = setup();
+		let base = add_material(&mut app, CustomMaterial::default());
+		let entity = app
+			.world_mut()
+			.spawn((base.clone(), MaterialOverrides::default()))
+			.id();
+
+		app.update();
+		let instance = app
+			.world()
+			.entity(entity)
+			.get::<Handle<CustomMaterial>>()
+			.cloned();
+		app.world_mut()
+			.entity_mut(entity)
+			.insert(MaterialOverrides {
+				tint: LinearRgba::RED,
+				..default()
+			});
+		app.update();
+
+		assert_eq!(
+			(instance.as_ref(), Some(LinearRgba::RED)),
+			(
+				app.world().entity(entity).get::<Handle<CustomMaterial>>(),
+				material_of(&app, entity).map(|m| m.color)
+			)
+		);
+	}
+
+	#[test]
+	fn update_instance_when_base_changes() {
+		let mut app = setup();
+		let base = add_material(&mut app, CustomMaterial::default());
+		let entity = app
+			.world_mut()
+			.spawn((base.clone(), MaterialOverrides::default()))
+			.id();
+
+		app.update();
+		app.world_mut()
+			.resource_mut::<Assets<CustomMaterial>>()
+			.get_mut(&base)
+			.unwrap()
+			.emissive = LinearRgba::GREEN;
+		app.world_mut()
+			.send_event(AssetEvent::Modified { id: base.id() });
+		app.update();
+
+		assert_eq!(
+			Some(LinearRgba::GREEN),
+			material_of(&app, entity).map(|m| m.emissive)
+		);
+	}
+
+	#[test]
+	fn update_only_changed_instances_of_modified_bases() {
+		let mut app = setup();
+		app.add_systems(Last, Assets::<CustomMaterial>::asset_events);
+		let base = add_material(&mut app, CustomMaterial::default());
+		let other_base = add_material(&mut app, CustomMaterial::default());
+		let entity = app
+			.world_mut()
+			.spawn((base.clone(), MaterialOverrides::default()))
+			.id();
+		let other = app
+			.world_mut()
+			.spawn((other_base.clone(), MaterialOverrides::default()))
+			.id();
+		let instance_of = |app: &App, entity| {
+			app.world()
+				.entity(entity)
+				.get::<Handle<CustomMaterial>>()
+				.unwrap()
+				.id()
+		};
+
+		app.update();
+		let instances = [instance_of(&app, entity), instance_of(&app, other)];
+		let mut materials = app.world_mut().resource_mut::<Assets<CustomMaterial>>();
+		materials.get_mut(&base).unwrap().emissive = LinearRgba::GREEN;
+		materials.get_mut(&other_base).unwrap();
+		app.update();
+		app.update();
+
+		let events = app.world().resource::<Events<AssetEvent<CustomMaterial>>>();
+		let modified_instances = events
+			.get_reader()
+			.read(events)
+			.filter_map(|event| match event {
+				AssetEvent::Modified { id } if instances.contains(id) => Some(*id),
+				_ => None,
+			})
+			.collect::<Vec<_>>();
+		assert_eq!(vec![instances[0]], modified_instances);
+	}
+
+	#[test]
+	fn restore_base_when_overrides_removed() {
+		let mut app = setup();
+		let base = add_material(&mut app, CustomMaterial::default());
+		let entity = app
+			.world_mut()
+			.spawn((base.clone(), MaterialOverrides::default()))
+			.id();
+
+		app.update();
+		app.world_mut()
+			.entity_mut(entity)
+			.remove::<MaterialOverrides>();
+		app.update();
+
+		let entity = app.world().entity(entity);
+		assert_eq!(
+			(Some(&base), None),
+			(
+				entity.get::<Handle<CustomMaterial>>(),
+				entity.get::<MaterialInstance>()
+			)
+		);
+	}
+
+	#[test]
+	fn use_newly_inserted_material_as_base() {
+		let mut app = setup();
+		let base = add_material(&mut app, CustomMaterial::default());
+		let new_base = add_material(&mut app, CustomMaterial::default());
+		let entity = app
+			.world_mut()
+			.spawn((base.clone(), MaterialOverrides::default()))
+			.id();
+
+		app.update();
+		app.world_mut()
+			.entity_mut(entity)
+			.remove::<Handle<CustomMaterial>>();
+		app.world_mut().entity_mut(entity).insert(new_base.clone());
+		app.update();
+
+		let entity = app.world().entity(entity);
+		assert_eq!(
+			(true, Some(&new_base)),
+			(
+				entity.get::<Handle<CustomMaterial>>() != Some(&new_base),
+				entity.get::<MaterialInstance>().map(|i| &i.base)
+			)
+		);
+	}
+
+	#[test]
+	fn apply_time_scale_and_offset_on_top_of_base_time() {
+		let mut app = setup();
+		let base = add_material(
+			&mut app,
+			CustomMaterial {
+				time: MaterialTime {
+					scale: 2.,
+					offset: 1.,
+				},
+				..default()
+			},
+		);
+		let entity = app
+			.world_mut()
+			.spawn((
+				base.clone(),
+				MaterialOverrides {
+					time_scale: 3.,
+					time_offset: 4.,
+					..default()
+				},
+			))
+			.id();
+
+		app.update();
+
+		assert_eq!(
+			Some(MaterialTime {
+				scale: 6.,
+				offset: 9.,
+			}),
+			material_of(&app, entity).map(|m| m.time)
+		);
+	}
+
+	#[test]
+	fn freeze_time_when_paused() {
+		let mut app = setup();
+		app.insert_resource(ShaderTime::new(10., 0., 0));
+		let base = add_material(&mut app, CustomMaterial::default());
+		let entity = app
+			.world_mut()
+			.spawn((
+				base.clone(),
+				MaterialOverrides {
+					time_scale: 2.,
+					time_offset: 1.,
+					paused: true,
+					..default()
+				},
+			))
+			.id();
+
+		app.update();
+
+		assert_eq!(
+			Some(MaterialTime {
+				scale: 0.,
+				offset: 21.,
+			}),
+			material_of(&app, entity).map(|m| m.time)
+		);
+	}
+
+	#[test]
+	fn keep_frozen_time_while_paused() {
+		let mut app = setup();
+		app.insert_resource(ShaderTime::new(10., 0., 0));
+		let base = add_material(&mut app, CustomMaterial::default());
+		let paused = MaterialOverrides {
+			paused: true,
+			..default()
+		};
+		let entity = app.world_mut().spawn((base.clone(), paused)).id();
+
+		app.update();
+		app.insert_resource(ShaderTime::new(20., 0., 0));
+		app.world_mut()
+			.entity_mut(entity)
+			.insert(MaterialOverrides {
+				tint: LinearRgba::RED,
+				..paused
+			});
+		app.update();
+
+		assert_eq!(
+			Some(MaterialTime {
+				scale: 0.,
+				offset: 10.,
+			}),
+			material_of(&app, entity).map(|m| m.time)
+		);
+	}
+
+	#[test]
+	fn resume_time_when_unpaused() {
+		let mut app = setup();
+		app.insert_resource(ShaderTime::new(10., 0., 0));
+		let base = add_material(&mut app, CustomMaterial::default());
+		let entity = app
+			.world_mut()
+			.spawn((
+				base.clone(),
+				MaterialOverrides {
+					paused: true,
+					..default()
+				},
+			))
+			.id();
+
+		app.update();
+		app.world_mut()
+			.entity_mut(entity)
+			.insert(MaterialOverrides::default());
+		app.update();
+
+		assert_eq!(
+			(Some(MaterialTime::default()), Some(None)),
+			(
+				material_of(&app, entity).map(|m| m.time),
+				app.world()
+					.entity(entity)
+					.get::<MaterialInstance>()
+					.map(|i| i.paused_secs)
+			)
+		);
+	}
+}
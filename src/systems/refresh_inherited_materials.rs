@@ -0,0 +1,188 @@
+use crate::resources::InheritedMaterials;
+use bevy::{ecs::event::ManualEventReader, prelude::*};
+
+type Readers<'s, TSource, TTarget> = (
+	Local<'s, ManualEventReader<AssetEvent<TSource>>>,
+	Local<'s, ManualEventReader<AssetEvent<TTarget>>>,
+);
+
+type AssetEvents<'w, TSource, TTarget> = (
+	Option<Res<'w, Events<AssetEvent<TSource>>>>,
+	Option<Res<'w, Events<AssetEvent<TTarget>>>>,
+);
+
+/// Keeps [`InheritedMaterials`] in sync with their templates and sources, see
+/// [`InheritedMaterials::refresh`].
+pub fn refresh_inherited_materials<TSource: Material, TTarget: Material>(
+	inherited: Option<ResMut<InheritedMaterials<TSource, TTarget>>>,
+	(mut source_reader, mut target_reader): Readers<TSource, TTarget>,
+	(source_events, target_events): AssetEvents<TSource, TTarget>,
+	sources: Option<Res<Assets<TSource>>>,
+	targets: Option<ResMut<Assets<TTarget>>>,
+) {
+	let (Some(mut inherited), Some(source_events), Some(target_events)) =
+		(inherited, source_events, target_events)
+	else {
+		return;
+	};
+	let (Some(sources), Some(mut targets)) = (sources, targets) else {
+		return;
+	};
+
+	inherited.refresh(
+		source_reader.read(&source_events),
+		target_reader.read(&target_events),
+		&sources,
+		&mut targets,
+	);
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::{
+		components::{InheritMaterialProperties, ReplacementMaterial},
+		material::{material_time::MaterialTime, CustomMaterial},
+		systems::replace_material::replace_material,
+	};
+	use bevy::color::palettes::css::{BLUE, RED};
+
+	fn setup() -> App {
+		let mut app = App::new();
+		app.add_plugins((MinimalPlugins, AssetPlugin::default()));
+		app.init_asset::<StandardMaterial>();
+		app.init_asset::<CustomMaterial>();
+		app.init_resource::<InheritedMaterials<StandardMaterial, CustomMaterial>>();
+		app.add_systems(
+			Update,
+			(
+				refresh_inherited_materials::<StandardMaterial, CustomMaterial>,
+				replace_material::<StandardMaterial, CustomMaterial>,
+			)
+				.chain(),
+		);
+
+		app
+	}
+
+	fn spawn_replaced(
+		app: &mut App,
+		source: StandardMaterial,
+		template: CustomMaterial,
+	) -> (Entity, Handle<StandardMaterial>, Handle<CustomMaterial>) {
+		let source = app
+			.world_mut()
+			.resource_mut::<Assets<StandardMaterial>>()
+			.add(source);
+		let template = app
+			.world_mut()
+			.resource_mut::<Assets<CustomMaterial>>()
+			.add(template);
+		let parent = app
+			.world_mut()
+			.spawn((
+				ReplacementMaterial(template.clone()),
+				InheritMaterialProperties::default(),
+			))
+			.id();
+		let child = app
+			.world_mut()
+			.spawn(source.clone())
+			.set_parent(parent)
+			.id();
+
+		(child, source, template)
+	}
+
+	fn material_of(app: &App, entity: Entity) -> Option<&CustomMaterial> {
+		let handle = app.world().entity(entity).get::<Handle<CustomMaterial>>()?;
+		app.world().resource::<Assets<CustomMaterial>>().get(handle)
+	}
+
+	#[test]
+	fn update_derived_material_when_template_modified() {
+		let mut app = setup();
+		let (child, _, template) = spawn_replaced(
+			&mut app,
+			StandardMaterial::default(),
+			CustomMaterial::default(),
+		);
+
+		app.update();
+		let derived = app
+			.world()
+			.entity(child)
+			.get::<Handle<CustomMaterial>>()
+			.cloned();
+		app.world_mut()
+			.resource_mut::<Assets<CustomMaterial>>()
+			.get_mut(&template)
+			.unwrap()
+			.time = MaterialTime {
+			scale: 2.,
+			offset: 42.,
+		};
+		app.update();
+		app.update();
+
+		assert_eq!(
+			(
+				derived.as_ref(),
+				Some(MaterialTime {
+					scale: 2.,
+					offset: 42.,
+				})
+			),
+			(
+				app.world().entity(child).get::<Handle<CustomMaterial>>(),
+				material_of(&app, child).map(|m| m.time)
+			)
+		);
+	}
+
+	#[test]
+	fn update_derived_material_when_source_modified() {
+		let mut app = setup();
+		let (child, source, _) = spawn_replaced(
+			&mut app,
+			StandardMaterial {
+				base_color: RED.into(),
+				..default()
+			},
+			CustomMaterial::default(),
+		);
+
+		app.update();
+		app.world_mut()
+			.resource_mut::<Assets<StandardMaterial>>()
+			.get_mut(&source)
+			.unwrap()
+			.base_color = BLUE.into();
+		app.update();
+		app.update();
+
+		assert_eq!(
+			Some(LinearRgba::from(BLUE)),
+			material_of(&app, child).map(|m| m.color)
+		);
+	}
+
+	#[test]
+	fn free_derived_material_when_no_longer_used() {
+		let mut app = setup();
+		let (child, ..) = spawn_replaced(
+			&mut app,
+			StandardMaterial::default(),
+			CustomMaterial::default(),
+		);
+
+		app.update();
+		let with_derived = app.world().resource::<Assets<CustomMaterial>>().len();
+		app.world_mut().entity_mut(child).despawn();
+		app.update();
+		app.update();
+		let without_derived = app.world().resource::<Assets<CustomMaterial>>().len();
+
+		assert_eq!((2, 1), (with_derived, without_derived));
+	}
+}
@@ -0,0 +1,967 @@
+use crate::{
+	components::{
+		InheritMaterialProperties,
+		OriginalMaterial,
+		ReplacementMaterial,
+		ReplacementNames,
+		ReplacementRules,
+	},
+	resources::InheritedMaterials,
+};
+use bevy::{
+	asset::UntypedAssetId,
+	ecs::entity::EntityHashSet,
+	gltf::Gltf,
+	prelude::*,
+	utils::HashMap,
+};
+
+type Replacements<'a, TMaterial> = (
+	Option<&'a ReplacementMaterial<TMaterial>>,
+	Option<&'a ReplacementRules<TMaterial>>,
+	Option<&'a InheritMaterialProperties>,
+);
+
+type WithReplacements<TMaterial> = Or<(
+	With<ReplacementMaterial<TMaterial>>,
+	With<ReplacementRules<TMaterial>>,
+)>;
+
+pub(crate) type ChangedReplacements<TMaterial> = Or<(
+	Changed<ReplacementMaterial<TMaterial>>,
+	Changed<ReplacementRules<TMaterial>>,
+	Changed<InheritMaterialProperties>,
+)>;
+
+type RemovedReplacements<'w, 's, TMaterial> = (
+	RemovedComponents<'w, 's, ReplacementMaterial<TMaterial>>,
+	RemovedComponents<'w, 's, ReplacementRules<TMaterial>>,
+	RemovedComponents<'w, 's, InheritMaterialProperties>,
+);
+
+type NewMaterialsOrParents<TMaterial> = Or<(Added<Handle<TMaterial>>, Changed<Parent>)>;
+
+/// Counts the entities with a source material visited during the last run, only tracked
+/// while inserted.
+#[cfg(test)]
+#[derive(Resource, Debug, PartialEq, Default)]
+pub struct ReplacementVisits(pub usize);
+
+type Inheritance<'w, TSource, TTarget> = (
+	Option<ResMut<'w, InheritedMaterials<TSource, TTarget>>>,
+	Option<Res<'w, Assets<TSource>>>,
+	Option<ResMut<'w, Assets<TTarget>>>,
+);
+
+/// Only visits entities (and their descendants) that got a new material or parent, or that
+/// are below a replacement which was added, changed or removed. Entities waiting for
+/// inherited materials to load are visited again on the next run.
+///
+/// Replacement roots are tried from the nearest to the outermost ancestor, until one of
+/// them provides a replacement.
+#[allow(clippy::too_many_arguments)]
+pub fn replace_material<TSource: Material, TTarget: Material>(
+	mut commands: Commands,
+	mut pending: Local<EntityHashSet>,
+	mut gltf_material_names: Local<HashMap<UntypedAssetId, Box<str>>>,
+	new_entities: Query<Entity, NewMaterialsOrParents<TSource>>,
+	changed_replacements: Query<Entity, ChangedReplacements<TTarget>>,
+	(mut removed_materials, mut removed_rules, mut removed_inherits): RemovedReplacements<TTarget>,
+	replacements: Query<Replacements<TTarget>, WithReplacements<TTarget>>,
+	materials: Query<&Handle<TSource>>,
+	names: Query<&Name>,
+	parents: Query<&Parent>,
+	children: Query<&Children>,
+	gltfs: Option<Res<Assets<Gltf>>>,
+	(mut inherited, sources, mut targets): Inheritance<TSource, TTarget>,
+	#[cfg(test)] mut visits: Option<ResMut<ReplacementVisits>>,
+) {
+	let is_replacement_root = |entity: &Entity| replacements.contains(*entity);
+	let get_name = |entity| names.get(entity).ok().map(Name::as_str);
+
+	if let Some(gltfs) = gltfs.filter(|gltfs| gltfs.is_changed()) {
+		*gltf_material_names = gltf_material_name_map(&gltfs);
+	}
+
+	let changed = new_entities
+		.iter()
+		.chain(changed_replacements.iter())
+		.chain(removed_materials.read())
+		.chain(removed_rules.read())
+		.chain(removed_inherits.read());
+
+	for entity in changed {
+		pending.insert(entity);
+		pending.extend(children.iter_descendants(entity));
+	}
+
+	#[cfg(test)]
+	if let Some(visits) = visits.as_mut() {
+		visits.0 = 0;
+	}
+
+	pending.retain(|entity| {
+		let entity = *entity;
+		let Ok(material) = materials.get(entity) else {
+			return false;
+		};
+		#[cfg(test)]
+		if let Some(visits) = visits.as_mut() {
+			visits.0 += 1;
+		}
+		let material_name = gltf_material_names
+			.get(&material.id().untyped())
+			.map(AsRef::as_ref);
+		let replacement = parents
+			.iter_ancestors(entity)
+			.filter(is_replacement_root)
+			.find_map(|root| {
+				let (fallback, rules, inherit) = replacements.get(root).ok()?;
+				let rule_replacement = rules.and_then(|rules| {
+					rules.replacement_for(&ReplacementNames {
+						material: material_name,
+						mesh: get_name(entity),
+						nodes: parents
+							.iter_ancestors(entity)
+							.take_while(|ancestor| ancestor != &root)
+							.filter_map(get_name)
+							.collect(),
+					})
+				});
+				let handle = rule_replacement.or(fallback.map(|ReplacementMaterial(h)| h))?;
+				Some((handle, inherit))
+			});
+		let Some((handle, inherit)) = replacement else {
+			return false;
+		};
+		let handle = match (inherit, inherited.as_mut()) {
+			(Some(properties), Some(inherited)) => {
+				let (Some(sources), Some(targets)) = (&sources, targets.as_mut()) else {
+					return false;
+				};
+				let Some(derived) =
+					inherited.derive(handle, material, *properties, sources, targets)
+				else {
+					return true;
+				};
+				derived
+			}
+			_ => handle.clone(),
+		};
+		let Some(mut entity) = commands.get_entity(entity) else {
+			return false;
+		};
+
+		entity.insert((handle, OriginalMaterial(material.clone())));
+		entity.remove::<Handle<TSource>>();
+		false
+	});
+}
+
+fn gltf_material_name_map(gltfs: &Assets<Gltf>) -> HashMap<UntypedAssetId, Box<str>> {
+	gltfs
+		.iter()
+		.flat_map(|(_, gltf)| &gltf.named_materials)
+		.map(|(name, material)| (material.id().untyped(), name.clone()))
+		.collect()
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::{
+		components::{ReplacementMaterial, ReplacementRule},
+		material::{material_time::MaterialTime, CustomMaterial},
+	};
+	use bevy::{
+		app::App,
+		color::palettes::css::{BLUE, RED},
+		ecs::system::RunSystemOnce,
+		render::render_resource::AsBindGroup,
+		utils::HashMap,
+	};
+	use uuid::Uuid;
+
+	#[derive(Asset, TypePath, AsBindGroup, Clone)]
+	struct MyMaterial {}
+
+	impl Material for MyMaterial {}
+
+	fn setup() -> App {
+		App::new()
+	}
+
+	fn new_handle<T: Asset>() -> Handle<T> {
+		Handle::Weak(AssetId::Uuid {
+			uuid: Uuid::new_v4(),
+		})
+	}
+
+	#[test]
+	fn set_replacement_material() {
+		let mut app = setup();
+		let material = new_handle::<StandardMaterial>();
+		let replacement = new_handle::<CustomMaterial>();
+		let parent = app
+			.world_mut()
+			.spawn(ReplacementMaterial(replacement.clone()))
+			.id();
+		let child = app.world_mut().spawn(material).set_parent(parent).id();
+
+		app.world_mut()
+			.run_system_once(replace_material::<StandardMaterial, CustomMaterial>);
+
+		let child = app.world().entity(child);
+		assert_eq!(Some(&replacement), child.get::<Handle<CustomMaterial>>())
+	}
+
+	#[test]
+	fn do_not_set_replacement_material_when_no_standard_material() {
+		let mut app = setup();
+		let replacement = new_handle::<CustomMaterial>();
+		let parent = app
+			.world_mut()
+			.spawn(ReplacementMaterial(replacement.clone()))
+			.id();
+		let child = app.world_mut().spawn_empty().set_parent(parent).id();
+
+		app.world_mut()
+			.run_system_once(replace_material::<StandardMaterial, CustomMaterial>);
+
+		let child = app.world().entity(child);
+		assert_eq!(None, child.get::<Handle<CustomMaterial>>())
+	}
+
+	#[test]
+	fn do_not_set_replacement_material_when_not_parent() {
+		let mut app = setup();
+		let material = new_handle::<StandardMaterial>();
+		let replacement = new_handle::<CustomMaterial>();
+		app.world_mut()
+			.spawn(ReplacementMaterial(replacement.clone()));
+		let material = app.world_mut().spawn(material).id();
+
+		app.world_mut()
+			.run_system_once(replace_material::<StandardMaterial, CustomMaterial>);
+
+		let material = app.world().entity(material);
+		assert_eq!(None, material.get::<Handle<CustomMaterial>>())
+	}
+
+	#[test]
+	fn set_replacement_material_of_nth_parent() {
+		let mut app = setup();
+		let material = new_handle::<StandardMaterial>();
+		let replacement = new_handle::<CustomMaterial>();
+		let parent = app
+			.world_mut()
+			.spawn(ReplacementMaterial(replacement.clone()))
+			.id();
+		let child = app.world_mut().spawn_empty().set_parent(parent).id();
+		let child_child = app.world_mut().spawn(material).set_parent(child).id();
+
+		app.world_mut()
+			.run_system_once(replace_material::<StandardMaterial, CustomMaterial>);
+
+		let child_child = app.world().entity(child_child);
+		assert_eq!(
+			Some(&replacement),
+			child_child.get::<Handle<CustomMaterial>>()
+		)
+	}
+
+	#[test]
+	fn remove_standard_material() {
+		let mut app = setup();
+		let material = new_handle::<StandardMaterial>();
+		let replacement = new_handle::<CustomMaterial>();
+		let parent = app
+			.world_mut()
+			.spawn(ReplacementMaterial(replacement.clone()))
+			.id();
+		let child = app.world_mut().spawn(material).set_parent(parent).id();
+
+		app.world_mut()
+			.run_system_once(replace_material::<StandardMaterial, CustomMaterial>);
+
+		let child = app.world().entity(child);
+		assert_eq!(None, child.get::<Handle<StandardMaterial>>())
+	}
+
+	#[test]
+	fn store_original_material() {
+		let mut app = setup();
+		let material = new_handle::<StandardMaterial>();
+		let replacement = new_handle::<CustomMaterial>();
+		let parent = app
+			.world_mut()
+			.spawn(ReplacementMaterial(replacement.clone()))
+			.id();
+		let child = app
+			.world_mut()
+			.spawn(material.clone())
+			.set_parent(parent)
+			.id();
+
+		app.world_mut()
+			.run_system_once(replace_material::<StandardMaterial, CustomMaterial>);
+
+		let child = app.world().entity(child);
+		assert_eq!(
+			Some(&OriginalMaterial(material)),
+			child.get::<OriginalMaterial<StandardMaterial>>()
+		)
+	}
+
+	#[test]
+	fn set_replacement_material_for_other_material_types() {
+		let mut app = setup();
+		let material = new_handle::<CustomMaterial>();
+		let replacement = new_handle::<MyMaterial>();
+		let parent = app
+			.world_mut()
+			.spawn(ReplacementMaterial(replacement.clone()))
+			.id();
+		let child = app.world_mut().spawn(material).set_parent(parent).id();
+
+		app.world_mut()
+			.run_system_once(replace_material::<CustomMaterial, MyMaterial>);
+
+		let child = app.world().entity(child);
+		assert_eq!(
+			(Some(&replacement), None),
+			(
+				child.get::<Handle<MyMaterial>>(),
+				child.get::<Handle<CustomMaterial>>()
+			)
+		)
+	}
+
+	#[test]
+	fn do_not_set_replacement_material_of_other_target_type() {
+		let mut app = setup();
+		let material = new_handle::<StandardMaterial>();
+		let replacement = new_handle::<CustomMaterial>();
+		let parent = app
+			.world_mut()
+			.spawn(ReplacementMaterial(replacement.clone()))
+			.id();
+		let child = app
+			.world_mut()
+			.spawn(material.clone())
+			.set_parent(parent)
+			.id();
+
+		app.world_mut()
+			.run_system_once(replace_material::<StandardMaterial, MyMaterial>);
+
+		let child = app.world().entity(child);
+		assert_eq!(
+			(Some(&material), None),
+			(
+				child.get::<Handle<StandardMaterial>>(),
+				child.get::<Handle<MyMaterial>>()
+			)
+		)
+	}
+
+	fn gltf_with_materials(materials: &[(&str, Handle<StandardMaterial>)]) -> Gltf {
+		Gltf {
+			scenes: vec![],
+			named_scenes: HashMap::default(),
+			meshes: vec![],
+			named_meshes: HashMap::default(),
+			materials: materials.iter().map(|(_, h)| h.clone()).collect(),
+			named_materials: materials
+				.iter()
+				.map(|(name, h)| (Box::from(*name), h.clone()))
+				.collect(),
+			nodes: vec![],
+			named_nodes: HashMap::default(),
+			default_scene: None,
+			animations: vec![],
+			named_animations: HashMap::default(),
+			source: None,
+		}
+	}
+
+	#[test]
+	fn set_replacement_material_by_gltf_material_name() {
+		let mut app = setup();
+		let rim = new_handle::<StandardMaterial>();
+		let face = new_handle::<StandardMaterial>();
+		let rim_replacement = new_handle::<CustomMaterial>();
+		let face_replacement = new_handle::<CustomMaterial>();
+		let mut gltfs = Assets::<Gltf>::default();
+		gltfs.add(gltf_with_materials(&[
+			("Rim", rim.clone()),
+			("Face", face.clone()),
+		]));
+		app.insert_resource(gltfs);
+		let parent = app
+			.world_mut()
+			.spawn(ReplacementRules(vec![
+				ReplacementRule::material_name("Rim", rim_replacement.clone()),
+				ReplacementRule::material_name("Face", face_replacement.clone()),
+			]))
+			.id();
+		let rim = app.world_mut().spawn(rim).set_parent(parent).id();
+		let face = app.world_mut().spawn(face).set_parent(parent).id();
+
+		app.world_mut()
+			.run_system_once(replace_material::<StandardMaterial, CustomMaterial>);
+
+		assert_eq!(
+			(Some(&rim_replacement), Some(&face_replacement)),
+			(
+				app.world().entity(rim).get::<Handle<CustomMaterial>>(),
+				app.world().entity(face).get::<Handle<CustomMaterial>>(),
+			)
+		);
+	}
+
+	#[test]
+	fn set_replacement_material_by_mesh_name() {
+		let mut app = setup();
+		let material = new_handle::<StandardMaterial>();
+		let replacement = new_handle::<CustomMaterial>();
+		let parent = app
+			.world_mut()
+			.spawn(ReplacementRules(vec![ReplacementRule::mesh_name(
+				"Rim.*",
+				replacement.clone(),
+			)]))
+			.id();
+		let child = app
+			.world_mut()
+			.spawn((material, Name::from("Rim.0")))
+			.set_parent(parent)
+			.id();
+
+		app.world_mut()
+			.run_system_once(replace_material::<StandardMaterial, CustomMaterial>);
+
+		let child = app.world().entity(child);
+		assert_eq!(Some(&replacement), child.get::<Handle<CustomMaterial>>())
+	}
+
+	#[test]
+	fn set_replacement_material_by_node_name() {
+		let mut app = setup();
+		let material = new_handle::<StandardMaterial>();
+		let replacement = new_handle::<CustomMaterial>();
+		let parent = app
+			.world_mut()
+			.spawn(ReplacementRules(vec![ReplacementRule::node_name(
+				"Rim",
+				replacement.clone(),
+			)]))
+			.id();
+		let node = app
+			.world_mut()
+			.spawn(Name::from("Rim"))
+			.set_parent(parent)
+			.id();
+		let child = app.world_mut().spawn(material).set_parent(node).id();
+
+		app.world_mut()
+			.run_system_once(replace_material::<StandardMaterial, CustomMaterial>);
+
+		let child = app.world().entity(child);
+		assert_eq!(Some(&replacement), child.get::<Handle<CustomMaterial>>())
+	}
+
+	#[test]
+	fn do_not_match_node_names_above_rules() {
+		let mut app = setup();
+		let material = new_handle::<StandardMaterial>();
+		let replacement = new_handle::<CustomMaterial>();
+		let root = app.world_mut().spawn(Name::from("Rim")).id();
+		let parent = app
+			.world_mut()
+			.spawn(ReplacementRules(vec![ReplacementRule::node_name(
+				"Rim",
+				replacement.clone(),
+			)]))
+			.set_parent(root)
+			.id();
+		let child = app.world_mut().spawn(material).set_parent(parent).id();
+
+		app.world_mut()
+			.run_system_once(replace_material::<StandardMaterial, CustomMaterial>);
+
+		let child = app.world().entity(child);
+		assert_eq!(None, child.get::<Handle<CustomMaterial>>())
+	}
+
+	#[test]
+	fn use_replacement_material_as_fallback_for_rules() {
+		let mut app = setup();
+		let material = new_handle::<StandardMaterial>();
+		let rim_replacement = new_handle::<CustomMaterial>();
+		let fallback = new_handle::<CustomMaterial>();
+		let parent = app
+			.world_mut()
+			.spawn((
+				ReplacementMaterial(fallback.clone()),
+				ReplacementRules(vec![ReplacementRule::mesh_name(
+					"Rim",
+					rim_replacement.clone(),
+				)]),
+			))
+			.id();
+		let rim = app
+			.world_mut()
+			.spawn((material.clone(), Name::from("Rim")))
+			.set_parent(parent)
+			.id();
+		let face = app
+			.world_mut()
+			.spawn((material, Name::from("Face")))
+			.set_parent(parent)
+			.id();
+
+		app.world_mut()
+			.run_system_once(replace_material::<StandardMaterial, CustomMaterial>);
+
+		assert_eq!(
+			(Some(&rim_replacement), Some(&fallback)),
+			(
+				app.world().entity(rim).get::<Handle<CustomMaterial>>(),
+				app.world().entity(face).get::<Handle<CustomMaterial>>(),
+			)
+		);
+	}
+
+	#[test]
+	fn keep_standard_material_when_no_rule_matches_and_no_fallback() {
+		let mut app = setup();
+		let material = new_handle::<StandardMaterial>();
+		let replacement = new_handle::<CustomMaterial>();
+		let parent = app
+			.world_mut()
+			.spawn(ReplacementRules(vec![ReplacementRule::mesh_name(
+				"Rim",
+				replacement.clone(),
+			)]))
+			.id();
+		let child = app
+			.world_mut()
+			.spawn((material.clone(), Name::from("Face")))
+			.set_parent(parent)
+			.id();
+
+		app.world_mut()
+			.run_system_once(replace_material::<StandardMaterial, CustomMaterial>);
+
+		let child = app.world().entity(child);
+		assert_eq!(
+			(Some(&material), None),
+			(
+				child.get::<Handle<StandardMaterial>>(),
+				child.get::<Handle<CustomMaterial>>()
+			)
+		)
+	}
+
+	#[test]
+	fn use_nearest_replacement_root() {
+		let mut app = setup();
+		let material = new_handle::<StandardMaterial>();
+		let outer = new_handle::<CustomMaterial>();
+		let inner = new_handle::<CustomMaterial>();
+		let root = app
+			.world_mut()
+			.spawn(ReplacementMaterial(outer.clone()))
+			.id();
+		let parent = app
+			.world_mut()
+			.spawn(ReplacementRules(vec![ReplacementRule::mesh_name(
+				"*",
+				inner.clone(),
+			)]))
+			.set_parent(root)
+			.id();
+		let child = app
+			.world_mut()
+			.spawn((material, Name::from("Face")))
+			.set_parent(parent)
+			.id();
+
+		app.world_mut()
+			.run_system_once(replace_material::<StandardMaterial, CustomMaterial>);
+
+		let child = app.world().entity(child);
+		assert_eq!(Some(&inner), child.get::<Handle<CustomMaterial>>())
+	}
+
+	#[test]
+	fn use_outer_replacement_root_when_nearest_does_not_match() {
+		let mut app = setup();
+		let material = new_handle::<StandardMaterial>();
+		let outer = new_handle::<CustomMaterial>();
+		let inner = new_handle::<CustomMaterial>();
+		let root = app
+			.world_mut()
+			.spawn(ReplacementMaterial(outer.clone()))
+			.id();
+		let parent = app
+			.world_mut()
+			.spawn(ReplacementRules(vec![ReplacementRule::mesh_name(
+				"Rim",
+				inner.clone(),
+			)]))
+			.set_parent(root)
+			.id();
+		let child = app
+			.world_mut()
+			.spawn((material, Name::from("Face")))
+			.set_parent(parent)
+			.id();
+
+		app.world_mut()
+			.run_system_once(replace_material::<StandardMaterial, CustomMaterial>);
+
+		let child = app.world().entity(child);
+		assert_eq!(Some(&outer), child.get::<Handle<CustomMaterial>>())
+	}
+
+	#[test]
+	fn match_gltf_material_added_after_first_run() {
+		let mut app = App::new();
+		app.init_resource::<Assets<Gltf>>();
+		app.add_systems(Update, replace_material::<StandardMaterial, CustomMaterial>);
+		let rim = new_handle::<StandardMaterial>();
+		let replacement = new_handle::<CustomMaterial>();
+		let parent = app
+			.world_mut()
+			.spawn(ReplacementRules(vec![ReplacementRule::material_name(
+				"Rim",
+				replacement.clone(),
+			)]))
+			.id();
+
+		app.update();
+		app.world_mut()
+			.resource_mut::<Assets<Gltf>>()
+			.add(gltf_with_materials(&[("Rim", rim.clone())]));
+		let rim = app.world_mut().spawn(rim).set_parent(parent).id();
+		app.update();
+
+		let rim = app.world().entity(rim);
+		assert_eq!(Some(&replacement), rim.get::<Handle<CustomMaterial>>())
+	}
+
+	fn setup_inheritance(app: &mut App) {
+		app.init_resource::<Assets<StandardMaterial>>();
+		app.init_resource::<Assets<CustomMaterial>>();
+		app.init_resource::<InheritedMaterials<StandardMaterial, CustomMaterial>>();
+	}
+
+	#[test]
+	fn set_inherited_replacement_material() {
+		let mut app = setup();
+		setup_inheritance(&mut app);
+		let material = app
+			.world_mut()
+			.resource_mut::<Assets<StandardMaterial>>()
+			.add(StandardMaterial {
+				base_color: RED.into(),
+				alpha_mode: AlphaMode::Mask(0.5),
+				..default()
+			});
+		let replacement = app
+			.world_mut()
+			.resource_mut::<Assets<CustomMaterial>>()
+			.add(CustomMaterial {
+				time: MaterialTime {
+					scale: 2.,
+					offset: 42.,
+				},
+				..default()
+			});
+		let parent = app
+			.world_mut()
+			.spawn((
+				ReplacementMaterial(replacement.clone()),
+				InheritMaterialProperties::default(),
+			))
+			.id();
+		let child = app.world_mut().spawn(material).set_parent(parent).id();
+
+		app.world_mut()
+			.run_system_once(replace_material::<StandardMaterial, CustomMaterial>);
+
+		let child = app.world().entity(child);
+		let materials = app.world().resource::<Assets<CustomMaterial>>();
+		let derived = child
+			.get::<Handle<CustomMaterial>>()
+			.and_then(|handle| materials.get(handle))
+			.map(|m| (m.color, m.alpha_mode, m.time));
+		assert_eq!(
+			Some((
+				LinearRgba::from(RED),
+				AlphaMode::Mask(0.5),
+				MaterialTime {
+					scale: 2.,
+					offset: 42.,
+				}
+			)),
+			derived
+		);
+	}
+
+	#[test]
+	fn share_inherited_material_between_identical_sources() {
+		let mut app = setup();
+		setup_inheritance(&mut app);
+		let material = app
+			.world_mut()
+			.resource_mut::<Assets<StandardMaterial>>()
+			.add(StandardMaterial::default());
+		let replacement = app
+			.world_mut()
+			.resource_mut::<Assets<CustomMaterial>>()
+			.add(CustomMaterial::default());
+		let parent = app
+			.world_mut()
+			.spawn((
+				ReplacementMaterial(replacement.clone()),
+				InheritMaterialProperties::default(),
+			))
+			.id();
+		let a = app
+			.world_mut()
+			.spawn(material.clone())
+			.set_parent(parent)
+			.id();
+		let b = app.world_mut().spawn(material).set_parent(parent).id();
+
+		app.world_mut()
+			.run_system_once(replace_material::<StandardMaterial, CustomMaterial>);
+
+		let a = app.world().entity(a).get::<Handle<CustomMaterial>>();
+		let b = app.world().entity(b).get::<Handle<CustomMaterial>>();
+		let materials = app.world().resource::<Assets<CustomMaterial>>();
+		assert_eq!((true, 2), (a.is_some() && a == b, materials.len()));
+	}
+
+	#[test]
+	fn derive_separate_materials_for_different_sources() {
+		let mut app = setup();
+		setup_inheritance(&mut app);
+		let mut materials = app.world_mut().resource_mut::<Assets<StandardMaterial>>();
+		let red = materials.add(StandardMaterial {
+			base_color: RED.into(),
+			..default()
+		});
+		let blue = materials.add(StandardMaterial {
+			base_color: BLUE.into(),
+			..default()
+		});
+		let replacement = app
+			.world_mut()
+			.resource_mut::<Assets<CustomMaterial>>()
+			.add(CustomMaterial::default());
+		let parent = app
+			.world_mut()
+			.spawn((
+				ReplacementMaterial(replacement.clone()),
+				InheritMaterialProperties::default(),
+			))
+			.id();
+		let red = app.world_mut().spawn(red).set_parent(parent).id();
+		let blue = app.world_mut().spawn(blue).set_parent(parent).id();
+
+		app.world_mut()
+			.run_system_once(replace_material::<StandardMaterial, CustomMaterial>);
+
+		let materials = app.world().resource::<Assets<CustomMaterial>>();
+		let get_color = |entity| {
+			app.world()
+				.entity(entity)
+				.get::<Handle<CustomMaterial>>()
+				.and_then(|handle| materials.get(handle))
+				.map(|m| m.color)
+		};
+		assert_eq!(
+			(Some(LinearRgba::from(RED)), Some(LinearRgba::from(BLUE))),
+			(get_color(red), get_color(blue))
+		);
+	}
+
+	#[test]
+	fn do_not_inherit_without_inherit_material_properties() {
+		let mut app = setup();
+		setup_inheritance(&mut app);
+		let material = app
+			.world_mut()
+			.resource_mut::<Assets<StandardMaterial>>()
+			.add(StandardMaterial::default());
+		let replacement = app
+			.world_mut()
+			.resource_mut::<Assets<CustomMaterial>>()
+			.add(CustomMaterial::default());
+		let parent = app
+			.world_mut()
+			.spawn(ReplacementMaterial(replacement.clone()))
+			.id();
+		let child = app.world_mut().spawn(material).set_parent(parent).id();
+
+		app.world_mut()
+			.run_system_once(replace_material::<StandardMaterial, CustomMaterial>);
+
+		let child = app.world().entity(child);
+		assert_eq!(Some(&replacement), child.get::<Handle<CustomMaterial>>());
+	}
+
+	#[test]
+	fn do_not_replace_while_inherited_source_not_loaded() {
+		let mut app = setup();
+		setup_inheritance(&mut app);
+		let material = new_handle::<StandardMaterial>();
+		let replacement = app
+			.world_mut()
+			.resource_mut::<Assets<CustomMaterial>>()
+			.add(CustomMaterial::default());
+		let parent = app
+			.world_mut()
+			.spawn((
+				ReplacementMaterial(replacement.clone()),
+				InheritMaterialProperties::default(),
+			))
+			.id();
+		let child = app
+			.world_mut()
+			.spawn(material.clone())
+			.set_parent(parent)
+			.id();
+
+		app.world_mut()
+			.run_system_once(replace_material::<StandardMaterial, CustomMaterial>);
+
+		let child = app.world().entity(child);
+		assert_eq!(
+			(Some(&material), None),
+			(
+				child.get::<Handle<StandardMaterial>>(),
+				child.get::<Handle<CustomMaterial>>()
+			)
+		);
+	}
+
+	fn setup_in_schedule() -> App {
+		let mut app = App::new();
+		app.add_systems(Update, replace_material::<StandardMaterial, CustomMaterial>);
+
+		app
+	}
+
+	#[test]
+	fn do_not_revisit_untouched_entities() {
+		let mut app = setup_in_schedule();
+		app.init_resource::<ReplacementVisits>();
+		let visits = |app: &App| app.world().resource::<ReplacementVisits>().0;
+		let parent = app
+			.world_mut()
+			.spawn(ReplacementMaterial(new_handle::<CustomMaterial>()))
+			.id();
+		for _ in 0..1000 {
+			app.world_mut()
+				.spawn(new_handle::<StandardMaterial>())
+				.set_parent(parent);
+		}
+
+		app.update();
+		let first = visits(&app);
+		app.update();
+		let untouched = visits(&app);
+		app.world_mut()
+			.spawn(new_handle::<StandardMaterial>())
+			.set_parent(parent);
+		app.update();
+		let new_child = visits(&app);
+
+		assert_eq!((1000, 0, 1), (first, untouched, new_child));
+	}
+
+	#[test]
+	fn replace_when_replacement_added_to_existing_hierarchy() {
+		let mut app = setup_in_schedule();
+		let material = new_handle::<StandardMaterial>();
+		let replacement = new_handle::<CustomMaterial>();
+		let parent = app.world_mut().spawn_empty().id();
+		let child = app.world_mut().spawn_empty().set_parent(parent).id();
+		let child_child = app.world_mut().spawn(material).set_parent(child).id();
+
+		app.update();
+		app.world_mut()
+			.entity_mut(parent)
+			.insert(ReplacementMaterial(replacement.clone()));
+		app.update();
+
+		let child_child = app.world().entity(child_child);
+		assert_eq!(
+			Some(&replacement),
+			child_child.get::<Handle<CustomMaterial>>()
+		);
+	}
+
+	#[test]
+	fn replace_when_subtree_moved_below_replacement() {
+		let mut app = setup_in_schedule();
+		let material = new_handle::<StandardMaterial>();
+		let replacement = new_handle::<CustomMaterial>();
+		let parent = app
+			.world_mut()
+			.spawn(ReplacementMaterial(replacement.clone()))
+			.id();
+		let node = app.world_mut().spawn_empty().id();
+		let child = app.world_mut().spawn(material).set_parent(node).id();
+
+		app.update();
+		app.world_mut().entity_mut(node).set_parent(parent);
+		app.update();
+
+		let child = app.world().entity(child);
+		assert_eq!(Some(&replacement), child.get::<Handle<CustomMaterial>>());
+	}
+
+	#[test]
+	fn replace_inherited_once_source_loaded() {
+		let mut app = setup_in_schedule();
+		setup_inheritance(&mut app);
+		let material = new_handle::<StandardMaterial>();
+		let replacement = app
+			.world_mut()
+			.resource_mut::<Assets<CustomMaterial>>()
+			.add(CustomMaterial::default());
+		let parent = app
+			.world_mut()
+			.spawn((
+				ReplacementMaterial(replacement.clone()),
+				InheritMaterialProperties::default(),
+			))
+			.id();
+		let child = app
+			.world_mut()
+			.spawn(material.clone())
+			.set_parent(parent)
+			.id();
+
+		app.update();
+		app.world_mut()
+			.resource_mut::<Assets<StandardMaterial>>()
+			.insert(material.id(), StandardMaterial::default());
+		app.update();
+
+		let child = app.world().entity(child);
+		assert_eq!(
+			(None, true),
+			(
+				child.get::<Handle<StandardMaterial>>(),
+				child.contains::<Handle<CustomMaterial>>(),
+			)
+		);
+	}
+}
@@ -0,0 +1,309 @@
+use super::replace_material::ChangedReplacements;
+use crate::components::{
+	InheritMaterialProperties,
+	MaterialInstance,
+	OriginalMaterial,
+	ReplacementMaterial,
+	ReplacementRules,
+};
+use bevy::prelude::*;
+
+pub fn restore_material<TSource: Material, TTarget: Material>(
+	mut commands: Commands,
+	changed: Query<Entity, ChangedReplacements<TTarget>>,
+	mut removed_materials: RemovedComponents<ReplacementMaterial<TTarget>>,
+	mut removed_rules: RemovedComponents<ReplacementRules<TTarget>>,
+	mut removed_inherits: RemovedComponents<InheritMaterialProperties>,
+	originals: Query<&OriginalMaterial<TSource>, With<Handle<TTarget>>>,
+	children: Query<&Children>,
+) {
+	let roots = changed
+		.iter()
+		.chain(removed_materials.read())
+		.chain(removed_rules.read())
+		.chain(removed_inherits.read());
+
+	for root in roots {
+		for entity in children.iter_descendants(root) {
+			let Ok(OriginalMaterial(original)) = originals.get(entity) else {
+				continue;
+			};
+			let Some(mut entity) = commands.get_entity(entity) else {
+				continue;
+			};
+
+			entity.insert(original.clone());
+			entity.remove::<(Handle<TTarget>, OriginalMaterial<TSource>, MaterialInstance)>();
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::{
+		components::ReplacementRule,
+		material::CustomMaterial,
+		systems::replace_material::replace_material,
+	};
+	use bevy::app::App;
+	use uuid::Uuid;
+
+	fn setup() -> App {
+		let mut app = App::new();
+		app.add_systems(
+			Update,
+			(
+				restore_material::<StandardMaterial, CustomMaterial>,
+				replace_material::<StandardMaterial, CustomMaterial>,
+			)
+				.chain(),
+		);
+
+		app
+	}
+
+	fn new_handle<T: Asset>() -> Handle<T> {
+		Handle::Weak(AssetId::Uuid {
+			uuid: Uuid::new_v4(),
+		})
+	}
+
+	#[test]
+	fn restore_original_material_when_replacement_removed() {
+		let mut app = setup();
+		let material = new_handle::<StandardMaterial>();
+		let replacement = new_handle::<CustomMaterial>();
+		let parent = app
+			.world_mut()
+			.spawn(ReplacementMaterial(replacement.clone()))
+			.id();
+		let child = app
+			.world_mut()
+			.spawn(material.clone())
+			.set_parent(parent)
+			.id();
+
+		app.update();
+		app.world_mut()
+			.entity_mut(parent)
+			.remove::<ReplacementMaterial<CustomMaterial>>();
+		app.update();
+
+		let child = app.world().entity(child);
+		assert_eq!(
+			(Some(&material), None, None),
+			(
+				child.get::<Handle<StandardMaterial>>(),
+				child.get::<Handle<CustomMaterial>>(),
+				child.get::<OriginalMaterial<StandardMaterial>>(),
+			)
+		);
+	}
+
+	#[test]
+	fn remove_material_instance_when_restoring() {
+		let mut app = setup();
+		let material = new_handle::<StandardMaterial>();
+		let replacement = new_handle::<CustomMaterial>();
+		let parent = app
+			.world_mut()
+			.spawn(ReplacementMaterial(replacement.clone()))
+			.id();
+		let child = app.world_mut().spawn(material).set_parent(parent).id();
+
+		app.update();
+		app.world_mut().entity_mut(child).insert(MaterialInstance {
+			base: replacement,
+			overrides: default(),
+			paused_secs: None,
+		});
+		app.world_mut()
+			.entity_mut(parent)
+			.remove::<ReplacementMaterial<CustomMaterial>>();
+		app.update();
+
+		let child = app.world().entity(child);
+		assert_eq!(None, child.get::<MaterialInstance>());
+	}
+
+	#[test]
+	fn restore_original_material_when_rules_removed() {
+		let mut app = setup();
+		let material = new_handle::<StandardMaterial>();
+		let replacement = new_handle::<CustomMaterial>();
+		let parent = app
+			.world_mut()
+			.spawn(ReplacementRules(vec![ReplacementRule::mesh_name(
+				"*",
+				replacement.clone(),
+			)]))
+			.id();
+		let child = app
+			.world_mut()
+			.spawn((material.clone(), Name::from("Rim")))
+			.set_parent(parent)
+			.id();
+
+		app.update();
+		app.world_mut()
+			.entity_mut(parent)
+			.remove::<ReplacementRules<CustomMaterial>>();
+		app.update();
+
+		let child = app.world().entity(child);
+		assert_eq!(
+			(Some(&material), None),
+			(
+				child.get::<Handle<StandardMaterial>>(),
+				child.get::<Handle<CustomMaterial>>(),
+			)
+		);
+	}
+
+	#[test]
+	fn use_changed_replacement_material() {
+		let mut app = setup();
+		let material = new_handle::<StandardMaterial>();
+		let replacement = new_handle::<CustomMaterial>();
+		let changed_replacement = new_handle::<CustomMaterial>();
+		let parent = app
+			.world_mut()
+			.spawn(ReplacementMaterial(replacement.clone()))
+			.id();
+		let child = app
+			.world_mut()
+			.spawn(material.clone())
+			.set_parent(parent)
+			.id();
+
+		app.update();
+		app.world_mut()
+			.entity_mut(parent)
+			.insert(ReplacementMaterial(changed_replacement.clone()));
+		app.update();
+
+		let child = app.world().entity(child);
+		assert_eq!(
+			(
+				None,
+				Some(&changed_replacement),
+				Some(&OriginalMaterial(material))
+			),
+			(
+				child.get::<Handle<StandardMaterial>>(),
+				child.get::<Handle<CustomMaterial>>(),
+				child.get::<OriginalMaterial<StandardMaterial>>(),
+			)
+		);
+	}
+
+	#[test]
+	fn use_changed_replacement_material_for_nth_child() {
+		let mut app = setup();
+		let material = new_handle::<StandardMaterial>();
+		let replacement = new_handle::<CustomMaterial>();
+		let changed_replacement = new_handle::<CustomMaterial>();
+		let parent = app
+			.world_mut()
+			.spawn(ReplacementMaterial(replacement.clone()))
+			.id();
+		let child = app.world_mut().spawn_empty().set_parent(parent).id();
+		let child_child = app.world_mut().spawn(material).set_parent(child).id();
+
+		app.update();
+		app.world_mut()
+			.entity_mut(parent)
+			.insert(ReplacementMaterial(changed_replacement.clone()));
+		app.update();
+
+		let child_child = app.world().entity(child_child);
+		assert_eq!(
+			Some(&changed_replacement),
+			child_child.get::<Handle<CustomMaterial>>()
+		);
+	}
+
+	#[test]
+	fn do_not_restore_when_replacement_unchanged() {
+		let mut app = App::new();
+		app.add_systems(Update, restore_material::<StandardMaterial, CustomMaterial>);
+		let material = new_handle::<StandardMaterial>();
+		let replacement = new_handle::<CustomMaterial>();
+		let parent = app
+			.world_mut()
+			.spawn(ReplacementMaterial(replacement.clone()))
+			.id();
+
+		app.update();
+		let child = app
+			.world_mut()
+			.spawn((replacement.clone(), OriginalMaterial(material)))
+			.set_parent(parent)
+			.id();
+		app.update();
+
+		let child = app.world().entity(child);
+		assert_eq!(
+			(None, Some(&replacement)),
+			(
+				child.get::<Handle<StandardMaterial>>(),
+				child.get::<Handle<CustomMaterial>>(),
+			)
+		);
+	}
+
+	#[test]
+	fn do_not_restore_descendants_of_other_roots() {
+		let mut app = setup();
+		let material = new_handle::<StandardMaterial>();
+		let replacement = new_handle::<CustomMaterial>();
+		let parent = app
+			.world_mut()
+			.spawn(ReplacementMaterial(replacement.clone()))
+			.id();
+		let other = app
+			.world_mut()
+			.spawn(ReplacementMaterial(replacement.clone()))
+			.id();
+		let child = app.world_mut().spawn(material).set_parent(other).id();
+
+		app.update();
+		app.world_mut()
+			.entity_mut(parent)
+			.remove::<ReplacementMaterial<CustomMaterial>>();
+		app.update();
+
+		let child = app.world().entity(child);
+		assert_eq!(Some(&replacement), child.get::<Handle<CustomMaterial>>());
+	}
+
+	#[test]
+	fn do_not_restore_other_target_materials() {
+		let mut app = setup();
+		let material = new_handle::<StandardMaterial>();
+		let replacement = new_handle::<CustomMaterial>();
+		let other_replacement = new_handle::<StandardMaterial>();
+		let parent = app
+			.world_mut()
+			.spawn(ReplacementMaterial(replacement.clone()))
+			.id();
+
+		app.update();
+		let child = app
+			.world_mut()
+			.spawn((other_replacement.clone(), OriginalMaterial(material)))
+			.set_parent(parent)
+			.id();
+		app.world_mut()
+			.entity_mut(parent)
+			.remove::<ReplacementMaterial<CustomMaterial>>();
+		app.update();
+
+		let child = app.world().entity(child);
+		assert_eq!(
+			Some(&other_replacement),
+			child.get::<Handle<StandardMaterial>>()
+		);
+	}
+}
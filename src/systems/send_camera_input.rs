@@ -0,0 +1,289 @@
+use crate::{
+	events::{OrbitMotion, PanMotion, ZoomMotion},
+	resources::{CameraInput, CameraInputBindings},
+};
+use bevy::prelude::*;
+
+/// Sends the camera movement of held keys and deflected gamepad axes and buttons
+/// according to the [`CameraInputBindings`], summed up to one event per kind.
+#[allow(clippy::too_many_arguments)]
+pub fn send_camera_input(
+	bindings: Res<CameraInputBindings>,
+	keys: Res<ButtonInput<KeyCode>>,
+	gamepads: Res<Gamepads>,
+	axes: Res<Axis<GamepadAxis>>,
+	buttons: Res<Axis<GamepadButton>>,
+	mut orbits: EventWriter<OrbitMotion>,
+	mut pans: EventWriter<PanMotion>,
+	mut zooms: EventWriter<ZoomMotion>,
+) {
+	let key_inputs = bindings
+		.keys
+		.iter()
+		.filter(|(key, _)| keys.pressed(*key))
+		.map(|(_, input)| *input);
+	let (axes, buttons) = (&*axes, &*buttons);
+	let gamepad_inputs = gamepads.iter().flat_map(|gamepad| {
+		let axis_inputs = bindings
+			.gamepad_axes
+			.iter()
+			.filter_map(move |(axis, input)| {
+				let value = axes.get(GamepadAxis::new(gamepad, *axis))?;
+				Some(input.scaled(value))
+			});
+		let button_inputs = bindings
+			.gamepad_buttons
+			.iter()
+			.filter_map(move |(button, input)| {
+				let value = buttons.get(GamepadButton::new(gamepad, *button))?;
+				Some(input.scaled(value))
+			});
+		axis_inputs.chain(button_inputs)
+	});
+
+	let (mut orbit, mut pan, mut zoom) = (Vec2::ZERO, Vec2::ZERO, 0.);
+	for input in key_inputs.chain(gamepad_inputs) {
+		match input {
+			CameraInput::Orbit(delta) => orbit += delta,
+			CameraInput::Pan(delta) => pan += delta,
+			CameraInput::Zoom(lines) => zoom += lines,
+		}
+	}
+
+	if orbit != Vec2::ZERO {
+		orbits.send(OrbitMotion { delta: orbit });
+	}
+	if pan != Vec2::ZERO {
+		pans.send(PanMotion { delta: pan });
+	}
+	if zoom != 0. {
+		zooms.send(ZoomMotion { lines: zoom });
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use bevy::{
+		app::App,
+		input::{
+			gamepad::{GamepadConnection, GamepadConnectionEvent, GamepadInfo},
+			InputPlugin,
+		},
+	};
+
+	const GAMEPAD: Gamepad = Gamepad { id: 0 };
+
+	fn setup(bindings: CameraInputBindings) -> App {
+		let mut app = App::new();
+		app.add_plugins(InputPlugin);
+		app.add_event::<OrbitMotion>();
+		app.add_event::<PanMotion>();
+		app.add_event::<ZoomMotion>();
+		app.insert_resource(bindings);
+		app.add_systems(Update, send_camera_input);
+
+		app
+	}
+
+	fn bindings() -> CameraInputBindings {
+		CameraInputBindings {
+			keys: vec![],
+			gamepad_axes: vec![],
+			gamepad_buttons: vec![],
+		}
+	}
+
+	fn connect_gamepad(app: &mut App) {
+		app.world_mut().send_event(GamepadConnectionEvent::new(
+			GAMEPAD,
+			GamepadConnection::Connected(GamepadInfo {
+				name: "Test Pad".to_owned(),
+			}),
+		));
+		app.update();
+	}
+
+	fn sent<TEvent: Event + Copy>(app: &App) -> Vec<TEvent> {
+		let events = app.world().resource::<Events<TEvent>>();
+		events.get_reader().read(events).copied().collect()
+	}
+
+	#[test]
+	fn send_orbit_motion_while_key_held() {
+		let mut app = setup(CameraInputBindings {
+			keys: vec![(KeyCode::ArrowLeft, CameraInput::Orbit(Vec2::new(-3., 0.)))],
+			..bindings()
+		});
+
+		app.world_mut()
+			.resource_mut::<ButtonInput<KeyCode>>()
+			.press(KeyCode::ArrowLeft);
+		app.update();
+
+		assert_eq!(
+			vec![OrbitMotion {
+				delta: Vec2::new(-3., 0.)
+			}],
+			sent::<OrbitMotion>(&app)
+		);
+	}
+
+	#[test]
+	fn send_nothing_when_no_key_held() {
+		let mut app = setup(CameraInputBindings {
+			keys: vec![
+				(KeyCode::ArrowLeft, CameraInput::Orbit(Vec2::new(-3., 0.))),
+				(KeyCode::KeyW, CameraInput::Pan(Vec2::new(0., 20.))),
+				(KeyCode::KeyE, CameraInput::Zoom(0.1)),
+			],
+			..bindings()
+		});
+
+		app.update();
+
+		assert_eq!(
+			(vec![], vec![], vec![]),
+			(
+				sent::<OrbitMotion>(&app),
+				sent::<PanMotion>(&app),
+				sent::<ZoomMotion>(&app),
+			)
+		);
+	}
+
+	#[test]
+	fn sum_held_keys_per_event_kind() {
+		let mut app = setup(CameraInputBindings {
+			keys: vec![
+				(KeyCode::KeyA, CameraInput::Pan(Vec2::new(20., 0.))),
+				(KeyCode::KeyW, CameraInput::Pan(Vec2::new(0., 20.))),
+				(KeyCode::KeyE, CameraInput::Zoom(0.1)),
+				(KeyCode::Equal, CameraInput::Zoom(0.1)),
+			],
+			..bindings()
+		});
+
+		let mut keys = app.world_mut().resource_mut::<ButtonInput<KeyCode>>();
+		keys.press(KeyCode::KeyA);
+		keys.press(KeyCode::KeyW);
+		keys.press(KeyCode::KeyE);
+		keys.press(KeyCode::Equal);
+		app.update();
+
+		assert_eq!(
+			(
+				vec![PanMotion {
+					delta: Vec2::new(20., 20.)
+				}],
+				vec![ZoomMotion { lines: 0.2 }]
+			),
+			(sent::<PanMotion>(&app), sent::<ZoomMotion>(&app))
+		);
+	}
+
+	#[test]
+	fn send_nothing_when_held_keys_cancel_out() {
+		let mut app = setup(CameraInputBindings {
+			keys: vec![
+				(KeyCode::KeyE, CameraInput::Zoom(0.1)),
+				(KeyCode::KeyQ, CameraInput::Zoom(-0.1)),
+			],
+			..bindings()
+		});
+
+		let mut keys = app.world_mut().resource_mut::<ButtonInput<KeyCode>>();
+		keys.press(KeyCode::KeyE);
+		keys.press(KeyCode::KeyQ);
+		app.update();
+
+		assert_eq!(vec![] as Vec<ZoomMotion>, sent::<ZoomMotion>(&app));
+	}
+
+	#[test]
+	fn scale_by_gamepad_axis() {
+		let mut app = setup(CameraInputBindings {
+			gamepad_axes: vec![(
+				GamepadAxisType::RightStickX,
+				CameraInput::Pan(Vec2::new(-20., 0.)),
+			)],
+			..bindings()
+		});
+		connect_gamepad(&mut app);
+
+		app.world_mut()
+			.resource_mut::<Axis<GamepadAxis>>()
+			.set(GamepadAxis::new(GAMEPAD, GamepadAxisType::RightStickX), 0.5);
+		app.update();
+
+		assert_eq!(
+			vec![PanMotion {
+				delta: Vec2::new(-10., 0.)
+			}],
+			sent::<PanMotion>(&app)
+		);
+	}
+
+	#[test]
+	fn scale_by_gamepad_button_axis() {
+		let mut app = setup(CameraInputBindings {
+			gamepad_buttons: vec![(GamepadButtonType::RightTrigger2, CameraInput::Zoom(0.1))],
+			..bindings()
+		});
+		connect_gamepad(&mut app);
+
+		app.world_mut().resource_mut::<Axis<GamepadButton>>().set(
+			GamepadButton::new(GAMEPAD, GamepadButtonType::RightTrigger2),
+			0.5,
+		);
+		app.update();
+
+		assert_eq!(vec![ZoomMotion { lines: 0.05 }], sent::<ZoomMotion>(&app));
+	}
+
+	#[test]
+	fn ignore_axes_of_disconnected_gamepads() {
+		let mut app = setup(CameraInputBindings {
+			gamepad_axes: vec![(
+				GamepadAxisType::LeftStickX,
+				CameraInput::Orbit(Vec2::new(3., 0.)),
+			)],
+			..bindings()
+		});
+
+		app.world_mut()
+			.resource_mut::<Axis<GamepadAxis>>()
+			.set(GamepadAxis::new(GAMEPAD, GamepadAxisType::LeftStickX), 1.);
+		app.update();
+
+		assert_eq!(vec![] as Vec<OrbitMotion>, sent::<OrbitMotion>(&app));
+	}
+
+	#[test]
+	fn combine_keys_and_gamepad() {
+		let mut app = setup(CameraInputBindings {
+			keys: vec![(KeyCode::ArrowUp, CameraInput::Orbit(Vec2::new(0., 3.)))],
+			gamepad_axes: vec![(
+				GamepadAxisType::LeftStickX,
+				CameraInput::Orbit(Vec2::new(3., 0.)),
+			)],
+			..bindings()
+		});
+		connect_gamepad(&mut app);
+
+		app.world_mut()
+			.resource_mut::<ButtonInput<KeyCode>>()
+			.press(KeyCode::ArrowUp);
+		app.world_mut()
+			.resource_mut::<Axis<GamepadAxis>>()
+			.set(GamepadAxis::new(GAMEPAD, GamepadAxisType::LeftStickX), -1.);
+		app.update();
+
+		assert_eq!(
+			vec![OrbitMotion {
+				delta: Vec2::new(-3., 3.)
+			}],
+			sent::<OrbitMotion>(&app)
+		);
+	}
+}
@@ -0,0 +1,55 @@
+use crate::events::PanMotion;
+use bevy::{input::mouse::MouseMotion, prelude::*};
+
+/// Turns mouse motion into [`PanMotion`], run it only while panning.
+pub fn send_pan_motion(mut motions: EventReader<MouseMotion>, mut pans: EventWriter<PanMotion>) {
+	pans.send_batch(motions.read().map(|motion| PanMotion {
+		delta: motion.delta,
+	}));
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use bevy::app::App;
+
+	fn setup() -> App {
+		let mut app = App::new();
+		app.add_event::<MouseMotion>();
+		app.add_event::<PanMotion>();
+		app.add_systems(Update, send_pan_motion);
+
+		app
+	}
+
+	#[test]
+	fn send_pan_motion_for_each_mouse_motion() {
+		let mut app = setup();
+
+		app.world_mut().send_event(MouseMotion {
+			delta: Vec2::new(1., 2.),
+		});
+		app.world_mut().send_event(MouseMotion {
+			delta: Vec2::new(3., 4.),
+		});
+		app.update();
+
+		let events = app.world().resource::<Events<PanMotion>>();
+		let pans = events
+			.get_reader()
+			.read(events)
+			.copied()
+			.collect::<Vec<_>>();
+		assert_eq!(
+			vec![
+				PanMotion {
+					delta: Vec2::new(1., 2.)
+				},
+				PanMotion {
+					delta: Vec2::new(3., 4.)
+				},
+			],
+			pans
+		);
+	}
+}
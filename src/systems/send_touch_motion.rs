@@ -0,0 +1,281 @@
+use crate::{
+	events::{OrbitMotion, PanMotion, TwistMotion, ZoomMotion},
+	resources::CameraZoomSettings,
+};
+use bevy::{
+	input::{gestures::PinchGesture, touch::TouchPhase},
+	prelude::*,
+	utils::HashMap,
+};
+
+/// Turns touch input into camera movement: dragging one finger orbits, dragging two
+/// fingers pans, spreading them zooms like a trackpad pinch and twisting them turns the
+/// camera.
+///
+/// Frames in which fingers are added or lifted are skipped, so switching between one and
+/// two fingers does not jump.
+pub fn send_touch_motion(
+	mut touches: EventReader<TouchInput>,
+	mut positions: Local<HashMap<u64, Vec2>>,
+	mut orbits: EventWriter<OrbitMotion>,
+	mut pans: EventWriter<PanMotion>,
+	mut zooms: EventWriter<ZoomMotion>,
+	mut twists: EventWriter<TwistMotion>,
+	settings: Res<CameraZoomSettings>,
+) {
+	let previous = positions.clone();
+
+	for touch in touches.read() {
+		match touch.phase {
+			TouchPhase::Started | TouchPhase::Moved => {
+				positions.insert(touch.id, touch.position);
+			}
+			TouchPhase::Ended | TouchPhase::Canceled => {
+				positions.remove(&touch.id);
+			}
+		}
+	}
+
+	let mut moves = positions
+		.iter()
+		.filter_map(|(id, position)| Some((*id, *previous.get(id)?, *position)))
+		.collect::<Vec<_>>();
+
+	if moves.len() != positions.len() || moves.len() != previous.len() {
+		return;
+	}
+
+	moves.sort_by_key(|(id, ..)| *id);
+
+	match moves.as_slice() {
+		[(_, from, to)] => {
+			let delta = *to - *from;
+			if delta != Vec2::ZERO {
+				orbits.send(OrbitMotion { delta });
+			}
+		}
+		[(_, a_from, a_to), (_, b_from, b_to)] => {
+			let pan = ((*a_to - *a_from) + (*b_to - *b_from)) / 2.;
+			let (before, after) = (*b_from - *a_from, *b_to - *a_to);
+
+			if pan != Vec2::ZERO {
+				pans.send(PanMotion { delta: pan });
+			}
+			if before == Vec2::ZERO || after == Vec2::ZERO {
+				return;
+			}
+
+			let pinch = after.length() / before.length() - 1.;
+			// screen space y points down, so a positive angle is a clockwise twist
+			let angle = -before.angle_between(after);
+
+			if pinch != 0. {
+				let lines = settings.pinched_lines(&PinchGesture(pinch));
+				zooms.send(ZoomMotion { lines });
+			}
+			if angle != 0. {
+				twists.send(TwistMotion { angle });
+			}
+		}
+		_ => {}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::assert_approx_eq;
+	use std::f32::consts::PI;
+
+	fn setup() -> App {
+		let mut app = App::new();
+		app.add_event::<TouchInput>();
+		app.add_event::<OrbitMotion>();
+		app.add_event::<PanMotion>();
+		app.add_event::<ZoomMotion>();
+		app.add_event::<TwistMotion>();
+		app.insert_resource(CameraZoomSettings {
+			lines_per_pinch: 2.,
+			..default()
+		});
+		app.add_systems(Update, send_touch_motion);
+
+		app
+	}
+
+	fn touch(app: &mut App, id: u64, phase: TouchPhase, x: f32, y: f32) {
+		app.world_mut().send_event(TouchInput {
+			phase,
+			position: Vec2::new(x, y),
+			window: Entity::from_raw(42),
+			force: None,
+			id,
+		});
+	}
+
+	fn sent<TEvent: Event + Copy>(app: &App) -> Vec<TEvent> {
+		let events = app.world().resource::<Events<TEvent>>();
+		events.get_reader().read(events).copied().collect()
+	}
+
+	fn clear_sent(app: &mut App) {
+		app.world_mut()
+			.resource_mut::<Events<OrbitMotion>>()
+			.clear();
+		app.world_mut().resource_mut::<Events<PanMotion>>().clear();
+		app.world_mut().resource_mut::<Events<ZoomMotion>>().clear();
+		app.world_mut()
+			.resource_mut::<Events<TwistMotion>>()
+			.clear();
+	}
+
+	fn two_fingers_down(app: &mut App, a: Vec2, b: Vec2) {
+		touch(app, 1, TouchPhase::Started, a.x, a.y);
+		touch(app, 2, TouchPhase::Started, b.x, b.y);
+		app.update();
+		clear_sent(app);
+	}
+
+	#[test]
+	fn orbit_when_dragging_one_finger() {
+		let mut app = setup();
+
+		touch(&mut app, 1, TouchPhase::Started, 10., 10.);
+		app.update();
+		touch(&mut app, 1, TouchPhase::Moved, 12., 11.);
+		touch(&mut app, 1, TouchPhase::Moved, 15., 13.);
+		app.update();
+
+		assert_eq!(
+			vec![OrbitMotion {
+				delta: Vec2::new(5., 3.)
+			}],
+			sent::<OrbitMotion>(&app)
+		);
+	}
+
+	#[test]
+	fn do_not_move_when_touching_down() {
+		let mut app = setup();
+
+		touch(&mut app, 1, TouchPhase::Started, 10., 10.);
+		app.update();
+
+		assert_eq!(vec![] as Vec<OrbitMotion>, sent::<OrbitMotion>(&app));
+	}
+
+	#[test]
+	fn do_not_move_when_resting() {
+		let mut app = setup();
+
+		touch(&mut app, 1, TouchPhase::Started, 10., 10.);
+		app.update();
+		touch(&mut app, 1, TouchPhase::Moved, 15., 13.);
+		app.update();
+		clear_sent(&mut app);
+		app.update();
+
+		assert_eq!(vec![] as Vec<OrbitMotion>, sent::<OrbitMotion>(&app));
+	}
+
+	#[test]
+	fn do_not_move_when_lifting_finger() {
+		let mut app = setup();
+
+		touch(&mut app, 1, TouchPhase::Started, 10., 10.);
+		app.update();
+		touch(&mut app, 1, TouchPhase::Moved, 15., 13.);
+		touch(&mut app, 1, TouchPhase::Ended, 15., 13.);
+		app.update();
+
+		assert_eq!(vec![] as Vec<OrbitMotion>, sent::<OrbitMotion>(&app));
+	}
+
+	#[test]
+	fn pan_when_dragging_two_fingers() {
+		let mut app = setup();
+		two_fingers_down(&mut app, Vec2::new(0., 0.), Vec2::new(10., 0.));
+
+		touch(&mut app, 1, TouchPhase::Moved, 2., 4.);
+		touch(&mut app, 2, TouchPhase::Moved, 12., 4.);
+		app.update();
+
+		assert_eq!(
+			(
+				vec![],
+				vec![PanMotion {
+					delta: Vec2::new(2., 4.)
+				}],
+				vec![],
+				vec![]
+			),
+			(
+				sent::<OrbitMotion>(&app),
+				sent::<PanMotion>(&app),
+				sent::<ZoomMotion>(&app),
+				sent::<TwistMotion>(&app),
+			)
+		);
+	}
+
+	#[test]
+	fn zoom_when_spreading_two_fingers() {
+		let mut app = setup();
+		two_fingers_down(&mut app, Vec2::new(0., 0.), Vec2::new(10., 0.));
+
+		touch(&mut app, 1, TouchPhase::Moved, -5., 0.);
+		touch(&mut app, 2, TouchPhase::Moved, 15., 0.);
+		app.update();
+
+		assert_eq!(
+			(vec![ZoomMotion { lines: 2. }], vec![]),
+			(sent::<ZoomMotion>(&app), sent::<PanMotion>(&app))
+		);
+	}
+
+	#[test]
+	fn twist_when_twisting_two_fingers() {
+		let mut app = setup();
+		two_fingers_down(&mut app, Vec2::new(-10., 0.), Vec2::new(10., 0.));
+
+		// clockwise on screen, where y points down
+		touch(&mut app, 1, TouchPhase::Moved, 0., -10.);
+		touch(&mut app, 2, TouchPhase::Moved, 0., 10.);
+		app.update();
+
+		let twists = sent::<TwistMotion>(&app);
+		assert_eq!(1, twists.len());
+		assert_approx_eq!(-PI / 2., twists[0].angle, 0.00001);
+	}
+
+	#[test]
+	fn do_not_orbit_when_adding_second_finger() {
+		let mut app = setup();
+
+		touch(&mut app, 1, TouchPhase::Started, 10., 10.);
+		app.update();
+		touch(&mut app, 1, TouchPhase::Moved, 15., 13.);
+		touch(&mut app, 2, TouchPhase::Started, 20., 20.);
+		app.update();
+
+		assert_eq!(vec![] as Vec<OrbitMotion>, sent::<OrbitMotion>(&app));
+	}
+
+	#[test]
+	fn orbit_with_remaining_finger_after_lifting_second() {
+		let mut app = setup();
+		two_fingers_down(&mut app, Vec2::new(0., 0.), Vec2::new(10., 0.));
+
+		touch(&mut app, 2, TouchPhase::Ended, 10., 0.);
+		app.update();
+		touch(&mut app, 1, TouchPhase::Moved, 3., 0.);
+		app.update();
+
+		assert_eq!(
+			vec![OrbitMotion {
+				delta: Vec2::new(3., 0.)
+			}],
+			sent::<OrbitMotion>(&app)
+		);
+	}
+}
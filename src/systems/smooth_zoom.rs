@@ -0,0 +1,329 @@
+use crate::{
+	components::{OrbitTarget, SmoothZoom},
+	events::ZoomMotion,
+	resources::CameraZoomSettings,
+};
+use bevy::{
+	input::{gestures::PinchGesture, mouse::MouseWheel},
+	prelude::*,
+};
+
+const ARRIVAL_DISTANCE: f32 = 0.001;
+
+/// Moves [`SmoothZoom`] cameras towards the distance requested by mouse wheel,
+/// [`PinchGesture`] and [`ZoomMotion`] input.
+///
+/// Each scrolled line moves the requested distance by the
+/// [`CameraZoomSettings::smooth_step`], whatever the frame rate. Cameras without an
+/// [`OrbitTarget`] zoom towards the default target.
+pub fn smooth_zoom(
+	time: Res<Time<Real>>,
+	settings: Res<CameraZoomSettings>,
+	mut cameras: Query<(&mut Transform, Option<&OrbitTarget>, &mut SmoothZoom), With<Camera>>,
+	transforms: Query<&GlobalTransform>,
+	mut wheels: EventReader<MouseWheel>,
+	mut zooms: EventReader<ZoomMotion>,
+	mut pinches: EventReader<PinchGesture>,
+) {
+	let delta = time.delta_seconds();
+	let lines = wheels
+		.read()
+		.map(|wheel| settings.scrolled_lines(wheel))
+		.chain(zooms.read().map(|zoom| zoom.lines))
+		.chain(pinches.read().map(|pinch| settings.pinched_lines(pinch)))
+		.collect::<Vec<_>>();
+
+	for (mut transform, target, mut zoom) in &mut cameras {
+		let target = target.copied().unwrap_or_default();
+		let Some(target) = target.position(&transforms) else {
+			continue;
+		};
+		let direction = transform.translation - target;
+		let distance = direction.length();
+
+		if distance == 0. {
+			continue;
+		}
+
+		for lines in &lines {
+			let target_distance = zoom.target_distance.unwrap_or(distance);
+			zoom.target_distance = Some(settings.smoothly_zoomed_distance(target_distance, *lines));
+		}
+
+		let Some(target_distance) = zoom.target_distance else {
+			continue;
+		};
+		let remaining = (distance - target_distance) * (-zoom.rate * delta).exp();
+		let distance = match remaining.abs() < ARRIVAL_DISTANCE {
+			true => {
+				zoom.target_distance = None;
+				target_distance
+			}
+			false => target_distance + remaining,
+		};
+
+		transform.translation = target + direction.normalize() * distance;
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::{
+		assert_approx_eq,
+		tools::test_tools::{frame, tick_time},
+	};
+	use bevy::input::mouse::MouseScrollUnit;
+	use std::time::Duration;
+
+	fn setup(settings: CameraZoomSettings) -> App {
+		let mut app = App::new();
+		app.init_resource::<Time<Real>>();
+		app.insert_resource(settings);
+		app.add_event::<MouseWheel>();
+		app.add_event::<ZoomMotion>();
+		app.add_event::<PinchGesture>();
+		app.add_systems(Update, smooth_zoom);
+
+		tick_time(&mut app, Duration::ZERO);
+		app
+	}
+
+	fn scroll_in(lines: f32) -> MouseWheel {
+		MouseWheel {
+			unit: MouseScrollUnit::Line,
+			x: 0.,
+			y: lines,
+			window: Entity::from_raw(42),
+		}
+	}
+
+	fn spawn_camera(app: &mut App, distance: f32, rate: f32) -> Entity {
+		app.world_mut()
+			.spawn((
+				Transform::from_xyz(distance, 0., 0.),
+				Camera::default(),
+				OrbitTarget::point(Vec3::ZERO),
+				SmoothZoom::new(rate),
+			))
+			.id()
+	}
+
+	fn distance_of(app: &App, camera: Entity) -> f32 {
+		app.world()
+			.entity(camera)
+			.get::<Transform>()
+			.unwrap()
+			.translation
+			.length()
+	}
+
+	#[test]
+	fn approach_target_distance() {
+		let mut app = setup(CameraZoomSettings {
+			smooth_step: 0.1,
+			..default()
+		});
+		let camera = spawn_camera(&mut app, 10., 2_f32.ln());
+
+		app.world_mut().send_event(scroll_in(2.));
+		frame(&mut app, Duration::from_millis(100));
+		frame(&mut app, Duration::from_secs(1));
+
+		let remaining_after_scroll = 2. * (-0.1 * 2_f32.ln()).exp();
+		assert_approx_eq!(
+			8. + remaining_after_scroll / 2.,
+			distance_of(&app, camera),
+			0.0001
+		);
+	}
+
+	#[test]
+	fn independent_from_frame_rate() {
+		let settings = CameraZoomSettings {
+			smooth_step: 0.1,
+			..default()
+		};
+		let mut slow = setup(settings);
+		let mut fast = setup(settings);
+		let slow_camera = spawn_camera(&mut slow, 10., 3.);
+		let fast_camera = spawn_camera(&mut fast, 10., 3.);
+		slow.world_mut().entity_mut(slow_camera).insert(SmoothZoom {
+			rate: 3.,
+			target_distance: Some(4.),
+		});
+		fast.world_mut().entity_mut(fast_camera).insert(SmoothZoom {
+			rate: 3.,
+			target_distance: Some(4.),
+		});
+
+		frame(&mut slow, Duration::from_millis(300));
+		for _ in 0..10 {
+			frame(&mut fast, Duration::from_millis(30));
+		}
+
+		assert_approx_eq!(
+			distance_of(&slow, slow_camera),
+			distance_of(&fast, fast_camera),
+			0.0001
+		);
+	}
+
+	#[test]
+	fn scroll_target_independent_from_frame_rate() {
+		let settings = CameraZoomSettings {
+			smooth_step: 0.1,
+			..default()
+		};
+		let mut slow = setup(settings);
+		let mut fast = setup(settings);
+		let slow_camera = spawn_camera(&mut slow, 10., 1.);
+		let fast_camera = spawn_camera(&mut fast, 10., 1.);
+
+		slow.world_mut().send_event(scroll_in(1.));
+		fast.world_mut().send_event(scroll_in(1.));
+		frame(&mut slow, Duration::from_millis(100));
+		frame(&mut fast, Duration::from_millis(16));
+
+		let slow_zoom = slow
+			.world()
+			.entity(slow_camera)
+			.get::<SmoothZoom>()
+			.unwrap();
+		let fast_zoom = fast
+			.world()
+			.entity(fast_camera)
+			.get::<SmoothZoom>()
+			.unwrap();
+		assert_eq!(
+			(Some(9.), Some(9.)),
+			(slow_zoom.target_distance, fast_zoom.target_distance)
+		);
+	}
+
+	#[test]
+	fn zoom_towards_default_target_without_target() {
+		let mut app = setup(CameraZoomSettings::default());
+		let camera = app
+			.world_mut()
+			.spawn((
+				Transform::from_xyz(0., 0.5, 10.),
+				Camera::default(),
+				SmoothZoom {
+					rate: 10.,
+					target_distance: Some(5.),
+				},
+			))
+			.id();
+
+		frame(&mut app, Duration::from_secs(10));
+
+		assert_eq!(
+			Vec3::new(0., 0.5, 5.),
+			app.world()
+				.entity(camera)
+				.get::<Transform>()
+				.unwrap()
+				.translation
+		);
+	}
+
+	#[test]
+	fn clamp_target_to_minimal_distance() {
+		let mut app = setup(CameraZoomSettings {
+			smooth_step: 0.1,
+			minimal_distance: 4.,
+			..default()
+		});
+		let camera = spawn_camera(&mut app, 10., 1.);
+
+		app.world_mut().send_event(scroll_in(10.));
+		frame(&mut app, Duration::from_secs(1));
+
+		let zoom = app.world().entity(camera).get::<SmoothZoom>().unwrap();
+		assert_eq!(Some(4.), zoom.target_distance);
+	}
+
+	#[test]
+	fn clamp_target_to_maximal_distance() {
+		let mut app = setup(CameraZoomSettings {
+			smooth_step: 0.1,
+			maximal_distance: 12.,
+			..default()
+		});
+		let camera = spawn_camera(&mut app, 10., 1.);
+
+		app.world_mut().send_event(scroll_in(-5.));
+		frame(&mut app, Duration::from_secs(1));
+
+		let zoom = app.world().entity(camera).get::<SmoothZoom>().unwrap();
+		assert_eq!(Some(12.), zoom.target_distance);
+	}
+
+	#[test]
+	fn accumulate_scrolling_on_target_distance() {
+		let mut app = setup(CameraZoomSettings {
+			smooth_step: 0.1,
+			..default()
+		});
+		let camera = spawn_camera(&mut app, 10., 1.);
+
+		app.world_mut().send_event(scroll_in(1.));
+		app.world_mut().send_event(scroll_in(1.));
+		frame(&mut app, Duration::from_millis(100));
+
+		let zoom = app.world().entity(camera).get::<SmoothZoom>().unwrap();
+		assert_approx_eq!(8.1, zoom.target_distance.unwrap(), 0.0001);
+	}
+
+	#[test]
+	fn accumulate_zoom_motion_on_target_distance() {
+		let mut app = setup(CameraZoomSettings {
+			smooth_step: 0.1,
+			..default()
+		});
+		let camera = spawn_camera(&mut app, 10., 1.);
+
+		app.world_mut().send_event(scroll_in(1.));
+		app.world_mut().send_event(ZoomMotion { lines: 1. });
+		frame(&mut app, Duration::from_millis(100));
+
+		let zoom = app.world().entity(camera).get::<SmoothZoom>().unwrap();
+		assert_approx_eq!(8.1, zoom.target_distance.unwrap(), 0.0001);
+	}
+
+	#[test]
+	fn accumulate_pinches_on_target_distance() {
+		let mut app = setup(CameraZoomSettings {
+			smooth_step: 0.1,
+			lines_per_pinch: 4.,
+			..default()
+		});
+		let camera = spawn_camera(&mut app, 10., 1.);
+
+		app.world_mut().send_event(PinchGesture(0.25));
+		app.world_mut().send_event(PinchGesture(0.25));
+		frame(&mut app, Duration::from_millis(100));
+
+		let zoom = app.world().entity(camera).get::<SmoothZoom>().unwrap();
+		assert_approx_eq!(8.1, zoom.target_distance.unwrap(), 0.0001);
+	}
+
+	#[test]
+	fn arrive_at_target_distance() {
+		let mut app = setup(CameraZoomSettings::default());
+		let camera = spawn_camera(&mut app, 10., 10.);
+		app.world_mut().entity_mut(camera).insert(SmoothZoom {
+			rate: 10.,
+			target_distance: Some(5.),
+		});
+
+		frame(&mut app, Duration::from_secs(10));
+
+		let zoom = app.world().entity(camera).get::<SmoothZoom>().unwrap();
+		assert_eq!(
+			(5., None),
+			(distance_of(&app, camera), zoom.target_distance)
+		);
+	}
+}
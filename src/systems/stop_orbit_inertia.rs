@@ -0,0 +1,73 @@
+use crate::{components::OrbitInertia, events::PanMotion};
+use bevy::prelude::*;
+
+/// Stops the [`OrbitInertia`] of cameras while they are panned, so they do not keep
+/// coasting around the moving orbit target.
+pub fn stop_orbit_inertia(
+	mut inertias: Query<&mut OrbitInertia, With<Camera>>,
+	mut pans: EventReader<PanMotion>,
+) {
+	if pans.read().count() == 0 {
+		return;
+	}
+
+	for mut inertia in &mut inertias {
+		inertia.velocity = Vec2::ZERO;
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use bevy::app::App;
+
+	fn setup() -> App {
+		let mut app = App::new();
+		app.add_event::<PanMotion>();
+		app.add_systems(Update, stop_orbit_inertia);
+
+		app
+	}
+
+	fn spawn_coasting_camera(app: &mut App) -> Entity {
+		app.world_mut()
+			.spawn((
+				Camera::default(),
+				OrbitInertia {
+					damping: 1.,
+					velocity: Vec2::new(1., 2.),
+				},
+			))
+			.id()
+	}
+
+	fn velocity_of(app: &App, camera: Entity) -> Option<Vec2> {
+		app.world()
+			.entity(camera)
+			.get::<OrbitInertia>()
+			.map(|i| i.velocity)
+	}
+
+	#[test]
+	fn stop_while_panning() {
+		let mut app = setup();
+		let camera = spawn_coasting_camera(&mut app);
+
+		app.world_mut().send_event(PanMotion {
+			delta: Vec2::new(1., 0.),
+		});
+		app.update();
+
+		assert_eq!(Some(Vec2::ZERO), velocity_of(&app, camera));
+	}
+
+	#[test]
+	fn keep_coasting_when_not_panning() {
+		let mut app = setup();
+		let camera = spawn_coasting_camera(&mut app);
+
+		app.update();
+
+		assert_eq!(Some(Vec2::new(1., 2.)), velocity_of(&app, camera));
+	}
+}
@@ -0,0 +1,158 @@
+use crate::components::OrbitTarget;
+use bevy::{prelude::*, render::camera::ScalingMode};
+
+/// Sets the scale of orthographic orbit cameras to their distance from the orbit target,
+/// so zooming by distance, like all camera controls do, shrinks and grows the view.
+///
+/// Only applies to a [`ScalingMode::FixedVertical`] of the visible height at distance 1,
+/// as the perspective toggle uses. Other scaling modes keep their scale. Cameras without
+/// an [`OrbitTarget`] use the default target.
+pub fn sync_orthographic_scale(
+	mut cameras: Query<(&Transform, Option<&OrbitTarget>, &mut Projection), With<Camera>>,
+	transforms: Query<&GlobalTransform>,
+) {
+	for (transform, target, mut projection) in &mut cameras {
+		let Projection::Orthographic(orthographic) = projection.as_ref() else {
+			continue;
+		};
+		let ScalingMode::FixedVertical(_) = orthographic.scaling_mode else {
+			continue;
+		};
+		let target = target.copied().unwrap_or_default();
+		let Some(anchor) = target.position(&transforms) else {
+			continue;
+		};
+		let distance = (transform.translation - anchor).length();
+
+		if orthographic.scale == distance {
+			continue;
+		}
+
+		let Projection::Orthographic(orthographic) = projection.as_mut() else {
+			continue;
+		};
+		orthographic.scale = distance;
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[derive(Resource, Default)]
+	struct ChangedProjections(usize);
+
+	fn count_changed(
+		mut changed: ResMut<ChangedProjections>,
+		projections: Query<(), Changed<Projection>>,
+	) {
+		changed.0 += projections.iter().count();
+	}
+
+	fn setup() -> App {
+		let mut app = App::new();
+		app.init_resource::<ChangedProjections>();
+		app.add_systems(Update, (sync_orthographic_scale, count_changed).chain());
+
+		app
+	}
+
+	fn spawn_camera(app: &mut App, distance: f32, projection: Projection) -> Entity {
+		app.world_mut()
+			.spawn((
+				Camera::default(),
+				Transform::from_xyz(0., 0., distance),
+				OrbitTarget::point(Vec3::ZERO),
+				projection,
+			))
+			.id()
+	}
+
+	fn fixed_vertical() -> Projection {
+		Projection::Orthographic(OrthographicProjection {
+			scaling_mode: ScalingMode::FixedVertical(2.),
+			..default()
+		})
+	}
+
+	fn scale_of(app: &App, camera: Entity) -> Option<f32> {
+		match app.world().entity(camera).get::<Projection>() {
+			Some(Projection::Orthographic(orthographic)) => Some(orthographic.scale),
+			_ => None,
+		}
+	}
+
+	#[test]
+	fn scale_by_distance() {
+		let mut app = setup();
+		let camera = spawn_camera(&mut app, 7., fixed_vertical());
+
+		app.update();
+
+		assert_eq!(Some(7.), scale_of(&app, camera));
+	}
+
+	#[test]
+	fn scale_by_distance_to_default_target_without_target() {
+		let mut app = setup();
+		let camera = app
+			.world_mut()
+			.spawn((
+				Camera::default(),
+				Transform::from_xyz(0., 0.5, 7.),
+				fixed_vertical(),
+			))
+			.id();
+
+		app.update();
+
+		assert_eq!(Some(7.), scale_of(&app, camera));
+	}
+
+	#[test]
+	fn ignore_other_scaling_modes() {
+		let mut app = setup();
+		let camera = spawn_camera(
+			&mut app,
+			7.,
+			Projection::Orthographic(OrthographicProjection {
+				scaling_mode: ScalingMode::WindowSize(100.),
+				scale: 2.,
+				..default()
+			}),
+		);
+
+		app.update();
+
+		assert_eq!(Some(2.), scale_of(&app, camera));
+	}
+
+	#[test]
+	fn ignore_perspective() {
+		let mut app = setup();
+		let camera = spawn_camera(
+			&mut app,
+			7.,
+			Projection::Perspective(PerspectiveProjection::default()),
+		);
+
+		app.update();
+
+		assert!(matches!(
+			app.world().entity(camera).get::<Projection>(),
+			Some(Projection::Perspective(_))
+		));
+	}
+
+	#[test]
+	fn do_not_change_projection_when_distance_unchanged() {
+		let mut app = setup();
+		spawn_camera(&mut app, 7., fixed_vertical());
+
+		app.update();
+		app.world_mut().resource_mut::<ChangedProjections>().0 = 0;
+		app.update();
+
+		assert_eq!(0, app.world().resource::<ChangedProjections>().0);
+	}
+}
@@ -0,0 +1,251 @@
+use crate::{components::OrbitTarget, resources::CameraProjectionSettings};
+use bevy::{prelude::*, render::camera::ScalingMode};
+
+/// Switches orbit cameras between perspective and orthographic projection, keeping what
+/// is visible at the orbit target's distance.
+///
+/// Cameras without an [`OrbitTarget`] use the default target.
+///
+/// The orthographic projection uses a [`ScalingMode::FixedVertical`] of the visible height
+/// at distance 1, so
+/// [`sync_orthographic_scale`](crate::systems::sync_orthographic_scale::sync_orthographic_scale)
+/// can keep zooming by distance.
+pub fn toggle_projection(
+	settings: Res<CameraProjectionSettings>,
+	keys: Res<ButtonInput<KeyCode>>,
+	mut cameras: Query<(&Transform, Option<&OrbitTarget>, &mut Projection), With<Camera>>,
+	transforms: Query<&GlobalTransform>,
+) {
+	if !keys.just_pressed(settings.toggle_key) {
+		return;
+	}
+
+	for (transform, target, mut projection) in &mut cameras {
+		let target = target.copied().unwrap_or_default();
+		let Some(anchor) = target.position(&transforms) else {
+			continue;
+		};
+		let distance = (transform.translation - anchor).length();
+
+		*projection = match projection.as_ref() {
+			Projection::Perspective(perspective) => {
+				Projection::Orthographic(orthographic_framing(perspective, distance))
+			}
+			Projection::Orthographic(orthographic) => {
+				Projection::Perspective(perspective_framing(orthographic))
+			}
+		};
+	}
+}
+
+/// Orthographic projection showing what `perspective` shows at `distance`.
+///
+/// The near plane lies behind the camera, because orthographic cameras only zoom by
+/// distance and should not clip what lies between them and the orbit target.
+fn orthographic_framing(
+	perspective: &PerspectiveProjection,
+	distance: f32,
+) -> OrthographicProjection {
+	OrthographicProjection {
+		near: -perspective.far,
+		far: perspective.far,
+		scaling_mode: ScalingMode::FixedVertical(2. * (perspective.fov / 2.).tan()),
+		scale: distance,
+		..default()
+	}
+}
+
+/// Perspective projection showing at the orbit target's distance what `orthographic`
+/// shows, when it was made by [`orthographic_framing`].
+fn perspective_framing(orthographic: &OrthographicProjection) -> PerspectiveProjection {
+	let default = PerspectiveProjection::default();
+	let fov = match orthographic.scaling_mode {
+		ScalingMode::FixedVertical(height) => 2. * (height / 2.).atan(),
+		_ => default.fov,
+	};
+
+	PerspectiveProjection {
+		fov,
+		far: orthographic.far,
+		..default
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::assert_approx_eq;
+	use std::f32::consts::PI;
+
+	fn setup(key: KeyCode) -> App {
+		let mut app = App::new();
+		app.init_resource::<ButtonInput<KeyCode>>();
+		app.insert_resource(CameraProjectionSettings { toggle_key: key });
+		app.add_systems(Update, toggle_projection);
+
+		app
+	}
+
+	fn spawn_camera(app: &mut App, projection: Projection) -> Entity {
+		app.world_mut()
+			.spawn((
+				Camera::default(),
+				Transform::from_xyz(0., 0., 4.),
+				OrbitTarget::point(Vec3::ZERO),
+				projection,
+			))
+			.id()
+	}
+
+	fn perspective(fov: f32) -> PerspectiveProjection {
+		PerspectiveProjection {
+			fov,
+			far: 100.,
+			..default()
+		}
+	}
+
+	#[test]
+	fn orthographic_framing_of_perspective() {
+		let orthographic = orthographic_framing(&perspective(PI / 2.), 4.);
+
+		let ScalingMode::FixedVertical(height) = orthographic.scaling_mode else {
+			panic!(
+				"expected fixed vertical scaling, got {:?}",
+				orthographic.scaling_mode
+			);
+		};
+		assert_approx_eq!(2., height, 0.00001);
+		assert_eq!(
+			(4., -100., 100.),
+			(orthographic.scale, orthographic.near, orthographic.far)
+		);
+	}
+
+	#[test]
+	fn perspective_framing_of_orthographic() {
+		let perspective = perspective_framing(&OrthographicProjection {
+			scaling_mode: ScalingMode::FixedVertical(2.),
+			far: 100.,
+			..default()
+		});
+
+		assert_approx_eq!(PI / 2., perspective.fov, 0.00001);
+		assert_eq!(100., perspective.far);
+	}
+
+	#[test]
+	fn perspective_framing_of_other_orthographic_uses_default_fov() {
+		let perspective = perspective_framing(&OrthographicProjection::default());
+
+		assert_eq!(PerspectiveProjection::default().fov, perspective.fov);
+	}
+
+	#[test]
+	fn framing_round_trip() {
+		let original = perspective(0.7);
+
+		let round_trip = perspective_framing(&orthographic_framing(&original, 4.));
+
+		assert_approx_eq!(original.fov, round_trip.fov, 0.00001);
+	}
+
+	#[test]
+	fn toggle_to_orthographic() {
+		let mut app = setup(KeyCode::KeyO);
+		let camera = spawn_camera(&mut app, Projection::Perspective(perspective(PI / 2.)));
+
+		app.world_mut()
+			.resource_mut::<ButtonInput<KeyCode>>()
+			.press(KeyCode::KeyO);
+		app.update();
+
+		let Some(Projection::Orthographic(orthographic)) =
+			app.world().entity(camera).get::<Projection>()
+		else {
+			panic!("expected orthographic projection");
+		};
+		assert_eq!(4., orthographic.scale);
+	}
+
+	#[test]
+	fn toggle_to_orthographic_at_default_target_distance_without_target() {
+		let mut app = setup(KeyCode::KeyO);
+		let camera = spawn_camera(&mut app, Projection::Perspective(perspective(PI / 2.)));
+		app.world_mut().entity_mut(camera).remove::<OrbitTarget>();
+
+		app.world_mut()
+			.resource_mut::<ButtonInput<KeyCode>>()
+			.press(KeyCode::KeyO);
+		app.update();
+
+		let Some(Projection::Orthographic(orthographic)) =
+			app.world().entity(camera).get::<Projection>()
+		else {
+			panic!("expected orthographic projection");
+		};
+		assert_approx_eq!(
+			Vec2::new(0., 0.5).extend(4.).length(),
+			orthographic.scale,
+			0.00001
+		);
+	}
+
+	#[test]
+	fn toggle_back_to_perspective() {
+		let mut app = setup(KeyCode::KeyO);
+		let camera = spawn_camera(&mut app, Projection::Perspective(perspective(0.7)));
+
+		let mut keys = app.world_mut().resource_mut::<ButtonInput<KeyCode>>();
+		keys.press(KeyCode::KeyO);
+		app.update();
+		let mut keys = app.world_mut().resource_mut::<ButtonInput<KeyCode>>();
+		keys.release(KeyCode::KeyO);
+		keys.clear();
+		keys.press(KeyCode::KeyO);
+		app.update();
+
+		let Some(Projection::Perspective(perspective)) =
+			app.world().entity(camera).get::<Projection>()
+		else {
+			panic!("expected perspective projection");
+		};
+		assert_approx_eq!(0.7, perspective.fov, 0.00001);
+	}
+
+	#[test]
+	fn do_not_toggle_without_key() {
+		let mut app = setup(KeyCode::KeyO);
+		let camera = spawn_camera(&mut app, Projection::Perspective(perspective(0.7)));
+
+		app.world_mut()
+			.resource_mut::<ButtonInput<KeyCode>>()
+			.press(KeyCode::KeyP);
+		app.update();
+
+		assert!(matches!(
+			app.world().entity(camera).get::<Projection>(),
+			Some(Projection::Perspective(_))
+		));
+	}
+
+	#[test]
+	fn do_not_toggle_when_held() {
+		let mut app = setup(KeyCode::KeyO);
+		let camera = spawn_camera(&mut app, Projection::Perspective(perspective(0.7)));
+
+		app.world_mut()
+			.resource_mut::<ButtonInput<KeyCode>>()
+			.press(KeyCode::KeyO);
+		app.update();
+		app.world_mut()
+			.resource_mut::<ButtonInput<KeyCode>>()
+			.clear();
+		app.update();
+
+		assert!(matches!(
+			app.world().entity(camera).get::<Projection>(),
+			Some(Projection::Orthographic(_))
+		));
+	}
+}
@@ -0,0 +1,133 @@
+use crate::resources::ShaderTime;
+use bevy::{core::FrameCount, prelude::*};
+
+pub fn update_shader_time<TTime: Default + Send + Sync + 'static>(
+	time: Res<Time<TTime>>,
+	frame_count: Res<FrameCount>,
+	mut shader_time: ResMut<ShaderTime>,
+) {
+	*shader_time = ShaderTime::new(
+		time.elapsed_seconds_wrapped(),
+		time.delta_seconds(),
+		frame_count.0,
+	);
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::tools::test_tools::tick_time;
+	use bevy::{
+		app::App,
+		ecs::system::RunSystemOnce,
+		time::{TimePlugin, TimeUpdateStrategy},
+	};
+	use std::time::Duration;
+
+	fn setup(frame_count: u32) -> App {
+		let mut app = App::new();
+		app.init_resource::<Time<Real>>();
+		app.init_resource::<ShaderTime>();
+		app.insert_resource(FrameCount(frame_count));
+
+		tick_time(&mut app, Duration::ZERO);
+		app
+	}
+
+	fn setup_virtual() -> App {
+		let mut app = App::new();
+		app.add_plugins(TimePlugin);
+		app.insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_secs(1)));
+		app.init_resource::<ShaderTime>();
+		app.init_resource::<FrameCount>();
+		app.add_systems(Update, update_shader_time::<Virtual>);
+
+		app
+	}
+
+	#[test]
+	fn set_elapsed_delta_and_frame_count() {
+		let mut app = setup(7);
+
+		tick_time(&mut app, Duration::from_secs(1));
+		tick_time(&mut app, Duration::from_secs(2));
+		app.world_mut().run_system_once(update_shader_time::<Real>);
+
+		let time = app.world().resource::<ShaderTime>();
+		assert_eq!(
+			(3., 2., 7),
+			(time.elapsed_secs, time.delta_secs, time.frame_count)
+		);
+	}
+
+	#[test]
+	fn set_sine_and_cosine_of_elapsed() {
+		let mut app = setup(0);
+
+		tick_time(&mut app, Duration::from_millis(500));
+		app.world_mut().run_system_once(update_shader_time::<Real>);
+
+		let time = app.world().resource::<ShaderTime>();
+		assert_eq!(
+			(0.5_f32.sin(), 0.5_f32.cos()),
+			(time.sin_time, time.cos_time)
+		);
+	}
+
+	#[test]
+	fn wrap_elapsed_after_one_hour() {
+		let mut app = setup(0);
+
+		tick_time(&mut app, Duration::from_secs(3600 + 2));
+		app.world_mut().run_system_once(update_shader_time::<Real>);
+
+		let time = app.world().resource::<ShaderTime>();
+		assert_eq!(2., time.elapsed_secs);
+	}
+
+	#[test]
+	fn set_elapsed_virtual_time() {
+		let mut app = setup_virtual();
+
+		app.update();
+		app.update();
+		app.update();
+
+		assert_eq!(
+			app.world().resource::<Time<Virtual>>().elapsed_seconds(),
+			app.world().resource::<ShaderTime>().elapsed_secs
+		);
+	}
+
+	#[test]
+	fn do_not_advance_shader_time_while_virtual_time_paused() {
+		let mut app = setup_virtual();
+
+		app.update();
+		app.update();
+		let before = app.world().resource::<ShaderTime>().elapsed_secs;
+		app.world_mut().resource_mut::<Time<Virtual>>().pause();
+		app.update();
+		app.update();
+
+		let time = app.world().resource::<ShaderTime>();
+		assert_eq!((before, 0.), (time.elapsed_secs, time.delta_secs));
+	}
+
+	#[derive(Default)]
+	struct CustomClock;
+
+	#[test]
+	fn set_elapsed_custom_clock_time() {
+		let mut app = setup(0);
+		app.init_resource::<Time<CustomClock>>();
+
+		app.world_mut()
+			.resource_mut::<Time<CustomClock>>()
+			.advance_by(Duration::from_secs(4));
+		app.world_mut()
+			.run_system_once(update_shader_time::<CustomClock>);
+
+		assert_eq!(4., app.world().resource::<ShaderTime>().elapsed_secs);
+	}
+}
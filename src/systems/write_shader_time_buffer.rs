@@ -0,0 +1,17 @@
+use crate::{material::shader_time_buffer::write_shader_time, resources::ShaderTime};
+use bevy::{
+	prelude::*,
+	render::renderer::{RenderDevice, RenderQueue},
+};
+
+/// Writes the extracted [`ShaderTime`] to the uniform buffer bound by crate materials.
+/// Without an extracted [`ShaderTime`], the shaders read a zero time.
+pub fn write_shader_time_buffer(
+	shader_time: Option<Res<ShaderTime>>,
+	render_device: Res<RenderDevice>,
+	render_queue: Res<RenderQueue>,
+) {
+	let shader_time = shader_time.map(|time| *time).unwrap_or_default();
+
+	write_shader_time(&shader_time, &render_device, &render_queue);
+}
@@ -1,3 +1,26 @@
+/// Declares a struct deriving [`ShaderType`](bevy::render::render_resource::ShaderType).
+///
+/// The derive emits layout checks per field, which are never called. They are allowed
+/// in a module holding only the derived struct, which is re-exported with the given
+/// visibility.
+macro_rules! shader_type {
+	($(#[$meta:meta])* $vis:vis struct $name:ident $fields:tt) => {
+		#[allow(dead_code)]
+		mod shader_type {
+			#[allow(unused_imports)]
+			use super::*;
+			use bevy::render::render_resource::ShaderType;
+
+			#[derive(ShaderType)]
+			$(#[$meta])*
+			pub struct $name $fields
+		}
+
+		$vis use shader_type::$name;
+	};
+}
+pub(crate) use shader_type;
+
 #[cfg(test)]
 pub mod test_tools {
 	use bevy::prelude::*;
@@ -21,4 +44,9 @@ pub mod test_tools {
 		let mut time = app.world_mut().resource_mut::<Time<Real>>();
 		time.advance_by(delta);
 	}
+
+	pub fn frame(app: &mut App, delta: Duration) {
+		tick_time(app, delta);
+		app.update();
+	}
 }
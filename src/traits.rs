@@ -1,2 +1,4 @@
 pub mod approx_eq;
+pub mod inherit_material;
+pub mod lerp;
 pub mod movement;
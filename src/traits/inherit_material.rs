@@ -0,0 +1,7 @@
+mod custom_material;
+
+use crate::components::InheritMaterialProperties;
+
+pub trait InheritMaterial<TSource> {
+	fn inherit(&self, source: &TSource, properties: InheritMaterialProperties) -> Self;
+}
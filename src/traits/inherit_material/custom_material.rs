@@ -0,0 +1,146 @@
+use super::InheritMaterial;
+use crate::{components::InheritMaterialProperties, material::CustomMaterial};
+use bevy::prelude::*;
+
+impl InheritMaterial<StandardMaterial> for CustomMaterial {
+	fn inherit(&self, source: &StandardMaterial, properties: InheritMaterialProperties) -> Self {
+		let InheritMaterialProperties {
+			normal_map,
+			emissive,
+		} = properties;
+
+		Self {
+			color: source.base_color.into(),
+			color_texture: source.base_color_texture.clone(),
+			alpha_mode: source.alpha_mode,
+			double_sided: source.double_sided,
+			normal_map_texture: match normal_map {
+				true => source.normal_map_texture.clone(),
+				false => self.normal_map_texture.clone(),
+			},
+			emissive: match emissive {
+				true => source.emissive,
+				false => self.emissive,
+			},
+			..self.clone()
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::material::material_time::MaterialTime;
+
+	fn image(id: u128) -> Handle<Image> {
+		Handle::weak_from_u128(id)
+	}
+
+	#[test]
+	fn inherit_base_properties() {
+		let material = CustomMaterial {
+			color: LinearRgba::RED,
+			color_texture: Some(image(1)),
+			alpha_mode: AlphaMode::Opaque,
+			time: MaterialTime {
+				scale: 2.,
+				offset: 42.,
+			},
+			..default()
+		};
+		let source = StandardMaterial {
+			base_color: Color::srgb(0.1, 0.2, 0.3),
+			base_color_texture: Some(image(2)),
+			alpha_mode: AlphaMode::Blend,
+			double_sided: true,
+			..default()
+		};
+
+		let material = material.inherit(&source, InheritMaterialProperties::default());
+
+		assert_eq!(
+			(
+				LinearRgba::from(Color::srgb(0.1, 0.2, 0.3)),
+				Some(image(2)),
+				AlphaMode::Blend,
+				true,
+				MaterialTime {
+					scale: 2.,
+					offset: 42.,
+				}
+			),
+			(
+				material.color,
+				material.color_texture,
+				material.alpha_mode,
+				material.double_sided,
+				material.time
+			)
+		);
+	}
+
+	#[test]
+	fn keep_normal_map_and_emissive() {
+		let material = CustomMaterial {
+			normal_map_texture: Some(image(1)),
+			emissive: LinearRgba::GREEN,
+			..default()
+		};
+		let source = StandardMaterial {
+			normal_map_texture: Some(image(2)),
+			emissive: LinearRgba::BLUE,
+			..default()
+		};
+
+		let material = material.inherit(&source, InheritMaterialProperties::default());
+
+		assert_eq!(
+			(Some(image(1)), LinearRgba::GREEN),
+			(material.normal_map_texture, material.emissive)
+		);
+	}
+
+	#[test]
+	fn inherit_normal_map() {
+		let material = CustomMaterial {
+			normal_map_texture: Some(image(1)),
+			..default()
+		};
+		let source = StandardMaterial {
+			normal_map_texture: Some(image(2)),
+			..default()
+		};
+
+		let material = material.inherit(
+			&source,
+			InheritMaterialProperties {
+				normal_map: true,
+				..default()
+			},
+		);
+
+		assert_eq!(Some(image(2)), material.normal_map_texture);
+	}
+
+	#[test]
+	fn inherit_emissive() {
+		let material = CustomMaterial {
+			emissive: LinearRgba::GREEN,
+			..default()
+		};
+		let source = StandardMaterial {
+			emissive: LinearRgba::BLUE,
+			..default()
+		};
+
+		let material = material.inherit(
+			&source,
+			InheritMaterialProperties {
+				emissive: true,
+				..default()
+			},
+		);
+
+		assert_eq!(LinearRgba::BLUE, material.emissive);
+	}
+}
@@ -0,0 +1,8 @@
+mod f32;
+
+/// Interpolation between two values, animated by a
+/// [`Transition`](crate::components::Transition).
+pub trait Lerp {
+	/// The value at `t` between `self` at 0 and `other` at 1.
+	fn lerp(&self, other: &Self, t: f32) -> Self;
+}
@@ -0,0 +1,20 @@
+use super::Lerp;
+
+impl Lerp for f32 {
+	fn lerp(&self, other: &Self, t: f32) -> Self {
+		self + (other - self) * t
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn lerp_between() {
+		assert_eq!(
+			(2., 3., 4.),
+			(2.0.lerp(&4., 0.), 2.0.lerp(&4., 0.5), 2.0.lerp(&4., 1.))
+		);
+	}
+}
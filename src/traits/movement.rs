@@ -1,7 +1,15 @@
 mod mouse_motion;
 mod mouse_wheel;
+mod orbit_motion;
+mod pan_motion;
+mod pinch_gesture;
+mod rotation_gesture;
+mod twist_motion;
+mod zoom_motion;
 
-use bevy::{math::Vec3, prelude::*};
+use crate::resources::CameraRotationSettings;
+use bevy::{ecs::query::QueryFilter, math::Vec3, prelude::*};
+use std::f32::consts::{PI, TAU};
 
 #[derive(Debug, PartialEq, Clone, Copy)]
 pub struct Seconds(pub f32);
@@ -9,8 +17,76 @@ pub struct Seconds(pub f32);
 #[derive(Debug, PartialEq, Clone, Copy)]
 pub struct Anchor(pub Vec3);
 
+/// Position of an agent orbiting an anchor, always facing the anchor upright.
+///
+/// Kept on cameras, so their yaw turns continuously past `PI` instead of wrapping like
+/// the yaw derived from a transform.
+#[derive(Component, Debug, PartialEq, Clone, Copy)]
+pub struct Orbit {
+	pub yaw: f32,
+	pub pitch: f32,
+	pub distance: f32,
+}
+
+impl Orbit {
+	pub fn from_transform(agent: &Transform, Anchor(anchor): Anchor) -> Self {
+		let offset = agent.translation - anchor;
+		let distance = offset.length();
+
+		if distance == 0. {
+			return Self {
+				yaw: 0.,
+				pitch: 0.,
+				distance,
+			};
+		}
+
+		Self {
+			yaw: offset.x.atan2(offset.z),
+			pitch: (offset.y / distance).clamp(-1., 1.).asin(),
+			distance,
+		}
+	}
+
+	/// Follows the `agent` moved by other means, turning the yaw by the shortest angle.
+	pub fn sync(&mut self, agent: &Transform, anchor: Anchor) {
+		let current = Self::from_transform(agent, anchor);
+
+		*self = Self {
+			yaw: closest_turn(current.yaw, self.yaw),
+			..current
+		};
+	}
+
+	/// Rotates within the pitch and yaw limits of the `settings`.
+	pub fn rotate(&mut self, yaw: f32, pitch: f32, settings: CameraRotationSettings) {
+		self.yaw += yaw;
+		self.pitch = (self.pitch + pitch).clamp(settings.min_pitch, settings.max_pitch);
+		if let Some((min_yaw, max_yaw)) = settings.yaw_limits {
+			let yaw = closest_turn(self.yaw, (min_yaw + max_yaw) / 2.);
+			self.yaw = yaw.clamp(min_yaw, max_yaw);
+		}
+	}
+
+	pub fn apply(&self, agent: &mut Transform, Anchor(anchor): Anchor) {
+		let (sin_yaw, cos_yaw) = self.yaw.sin_cos();
+		let (sin_pitch, cos_pitch) = self.pitch.sin_cos();
+		let offset = Vec3::new(cos_pitch * sin_yaw, sin_pitch, cos_pitch * cos_yaw);
+
+		agent.translation = anchor + offset * self.distance;
+		agent.look_at(anchor, Vec3::Y);
+	}
+}
+
+/// The `yaw` turned by full turns to be as close as possible to `reference`.
+fn closest_turn(yaw: f32, reference: f32) -> f32 {
+	reference + (yaw - reference + PI).rem_euclid(TAU) - PI
+}
+
 pub trait AnchoredMovement {
 	type TExtra;
+	/// Agents excluded from this movement, because they are moved in other ways
+	type TFilter: QueryFilter;
 	fn anchored_movement(
 		&self,
 		agent: &mut Transform,
@@ -18,4 +94,29 @@ pub trait AnchoredMovement {
 		delta: Seconds,
 		extra: Self::TExtra,
 	);
+
+	/// Moves the `agent` along its `orbit`, which is synced with the agent beforehand.
+	/// Rotating movements override it to turn the `orbit` itself.
+	fn orbit_movement(
+		&self,
+		agent: &mut Transform,
+		_orbit: &mut Orbit,
+		around: Anchor,
+		delta: Seconds,
+		extra: Self::TExtra,
+	) {
+		self.anchored_movement(agent, around, delta, extra);
+	}
+
+	/// Where `around` is moved to together with the `agent`, which moves the agent's orbit
+	/// target. Only movements carrying the anchor along, like panning, override it.
+	fn moved_anchor(
+		&self,
+		_agent: &Transform,
+		around: Anchor,
+		_delta: Seconds,
+		_extra: Self::TExtra,
+	) -> Anchor {
+		around
+	}
 }
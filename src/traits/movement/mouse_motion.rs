@@ -1,21 +1,37 @@
-use super::{Anchor, AnchoredMovement, Seconds};
-use crate::resources::CameraRotationSettings;
+use super::{Anchor, AnchoredMovement, Orbit, Seconds};
+use crate::{components::OrbitInertia, resources::CameraRotationSettings};
 use bevy::{input::mouse::MouseMotion, prelude::*};
 
 impl AnchoredMovement for MouseMotion {
 	type TExtra = CameraRotationSettings;
+	type TFilter = Without<OrbitInertia>;
 
 	fn anchored_movement(
 		&self,
 		agent: &mut Transform,
-		Anchor(anchor): Anchor,
+		anchor: Anchor,
+		delta: Seconds,
+		settings: CameraRotationSettings,
+	) {
+		let mut orbit = Orbit::from_transform(agent, anchor);
+		self.orbit_movement(agent, &mut orbit, anchor, delta, settings);
+	}
+
+	fn orbit_movement(
+		&self,
+		agent: &mut Transform,
+		orbit: &mut Orbit,
+		anchor: Anchor,
 		Seconds(delta): Seconds,
-		CameraRotationSettings { sensitivity }: CameraRotationSettings,
+		settings: CameraRotationSettings,
 	) {
-		let distance = (agent.translation - anchor).length();
-		agent.rotate_y(-self.delta.x * sensitivity * delta);
-		agent.rotate_local_x(-self.delta.y * sensitivity * delta);
-		agent.translation = anchor - agent.forward().as_vec3() * distance;
+		if orbit.distance == 0. {
+			return;
+		}
+
+		let rotation = settings.orbit_velocity(self) * delta;
+		orbit.rotate(rotation.x, rotation.y, settings);
+		orbit.apply(agent, anchor);
 	}
 }
 
@@ -52,7 +68,10 @@ mod test {
 			&mut agent,
 			Anchor(anchor),
 			Seconds(1.),
-			CameraRotationSettings { sensitivity: 1. },
+			CameraRotationSettings {
+				sensitivity: 1.,
+				..default()
+			},
 		);
 
 		assert_approx_eq!(
@@ -77,7 +96,10 @@ mod test {
 			&mut agent,
 			Anchor(anchor),
 			Seconds(1.),
-			CameraRotationSettings { sensitivity: 1. },
+			CameraRotationSettings {
+				sensitivity: 1.,
+				..default()
+			},
 		);
 
 		assert_approx_eq!(
@@ -102,7 +124,10 @@ mod test {
 			&mut agent,
 			Anchor(anchor),
 			Seconds(1.),
-			CameraRotationSettings { sensitivity: 0.5 },
+			CameraRotationSettings {
+				sensitivity: 0.5,
+				..default()
+			},
 		);
 
 		assert_approx_eq!(
@@ -127,7 +152,10 @@ mod test {
 			&mut agent,
 			Anchor(anchor),
 			Seconds(1.),
-			CameraRotationSettings { sensitivity: 1. },
+			CameraRotationSettings {
+				sensitivity: 1.,
+				..default()
+			},
 		);
 
 		assert_approx_eq!(
@@ -152,7 +180,10 @@ mod test {
 			&mut agent,
 			Anchor(anchor),
 			Seconds(0.5),
-			CameraRotationSettings { sensitivity: 1. },
+			CameraRotationSettings {
+				sensitivity: 1.,
+				..default()
+			},
 		);
 
 		assert_approx_eq!(
@@ -177,7 +208,10 @@ mod test {
 			&mut agent,
 			Anchor(anchor),
 			Seconds(1.),
-			CameraRotationSettings { sensitivity: 1. },
+			CameraRotationSettings {
+				sensitivity: 1.,
+				..default()
+			},
 		);
 
 		assert_approx_eq!(
@@ -202,7 +236,10 @@ mod test {
 			&mut agent,
 			Anchor(anchor),
 			Seconds(0.5),
-			CameraRotationSettings { sensitivity: 1. },
+			CameraRotationSettings {
+				sensitivity: 1.,
+				..default()
+			},
 		);
 
 		assert_approx_eq!(
@@ -227,7 +264,10 @@ mod test {
 			&mut agent,
 			Anchor(anchor),
 			Seconds(1.),
-			CameraRotationSettings { sensitivity: 0.5 },
+			CameraRotationSettings {
+				sensitivity: 0.5,
+				..default()
+			},
 		);
 
 		assert_approx_eq!(
@@ -236,4 +276,247 @@ mod test {
 			TOLERANCE
 		);
 	}
+
+	fn rotation_settings() -> CameraRotationSettings {
+		CameraRotationSettings {
+			sensitivity: 1.,
+			min_pitch: radians_from_degrees(-80.),
+			max_pitch: radians_from_degrees(80.),
+			yaw_limits: None,
+		}
+	}
+
+	fn orbit_position(yaw: f32, pitch: f32) -> Vec3 {
+		let (yaw, pitch) = (radians_from_degrees(yaw), radians_from_degrees(pitch));
+		Vec3::new(
+			pitch.cos() * yaw.sin(),
+			pitch.sin(),
+			pitch.cos() * yaw.cos(),
+		)
+	}
+
+	#[test]
+	fn clamp_pitch_when_rotating_over_upper_pole() {
+		let anchor = Vec3::new(0., 0., 0.);
+		let mut agent = Transform::from_xyz(1., 0., 0.).looking_at(anchor, UP);
+		let event = MouseMotion {
+			delta: Vec2 {
+				x: 0.,
+				y: radians_from_degrees(135.),
+			},
+		};
+
+		event.anchored_movement(&mut agent, Anchor(anchor), Seconds(1.), rotation_settings());
+
+		assert_approx_eq!(
+			Transform::from_translation(orbit_position(90., 80.)).looking_at(anchor, UP),
+			agent,
+			TOLERANCE
+		);
+	}
+
+	#[test]
+	fn clamp_pitch_when_rotating_over_lower_pole() {
+		let anchor = Vec3::new(0., 0., 0.);
+		let mut agent = Transform::from_xyz(1., 0., 0.).looking_at(anchor, UP);
+		let event = MouseMotion {
+			delta: Vec2 {
+				x: 0.,
+				y: radians_from_degrees(-135.),
+			},
+		};
+
+		event.anchored_movement(&mut agent, Anchor(anchor), Seconds(1.), rotation_settings());
+
+		assert_approx_eq!(
+			Transform::from_translation(orbit_position(90., -80.)).looking_at(anchor, UP),
+			agent,
+			TOLERANCE
+		);
+	}
+
+	#[test]
+	fn keep_agent_upright_at_pitch_limit() {
+		let anchor = Vec3::new(0., 0., 0.);
+		let mut agent = Transform::from_xyz(1., 0., 0.).looking_at(anchor, UP);
+		let event = MouseMotion {
+			delta: Vec2 {
+				x: 0.,
+				y: radians_from_degrees(45.),
+			},
+		};
+
+		for _ in 0..10 {
+			event.anchored_movement(&mut agent, Anchor(anchor), Seconds(1.), rotation_settings());
+		}
+
+		assert!(agent.up().y > 0.);
+	}
+
+	#[test]
+	fn rotate_away_from_pole() {
+		let anchor = Vec3::new(0., 0., 0.);
+		let mut agent = Transform::from_xyz(0., 1., 0.).looking_at(anchor, Vec3::Z);
+		let event = MouseMotion {
+			delta: Vec2 {
+				x: 0.,
+				y: radians_from_degrees(-10.),
+			},
+		};
+
+		event.anchored_movement(&mut agent, Anchor(anchor), Seconds(1.), rotation_settings());
+
+		assert_approx_eq!(
+			Transform::from_translation(orbit_position(0., 80.)).looking_at(anchor, UP),
+			agent,
+			TOLERANCE
+		);
+	}
+
+	#[test]
+	fn clamp_pitch_with_distance_and_offset_anchor() {
+		let anchor = Vec3::new(0., 1., 0.);
+		let mut agent = Transform::from_xyz(0., 1., 3.).looking_at(anchor, UP);
+		let event = MouseMotion {
+			delta: Vec2 {
+				x: 0.,
+				y: radians_from_degrees(100.),
+			},
+		};
+
+		event.anchored_movement(&mut agent, Anchor(anchor), Seconds(1.), rotation_settings());
+
+		assert_approx_eq!(
+			Transform::from_translation(anchor + orbit_position(0., 80.) * 3.)
+				.looking_at(anchor, UP),
+			agent,
+			TOLERANCE
+		);
+	}
+
+	#[test]
+	fn clamp_yaw_to_limits() {
+		let anchor = Vec3::new(0., 0., 0.);
+		let mut agent = Transform::from_xyz(0., 0., 1.).looking_at(anchor, UP);
+		let event = MouseMotion {
+			delta: Vec2 {
+				x: radians_from_degrees(-90.),
+				y: 0.,
+			},
+		};
+
+		event.anchored_movement(
+			&mut agent,
+			Anchor(anchor),
+			Seconds(1.),
+			CameraRotationSettings {
+				yaw_limits: Some((radians_from_degrees(-30.), radians_from_degrees(30.))),
+				..rotation_settings()
+			},
+		);
+
+		assert_approx_eq!(
+			Transform::from_translation(orbit_position(30., 0.)).looking_at(anchor, UP),
+			agent,
+			TOLERANCE
+		);
+	}
+
+	#[test]
+	fn rotate_past_pi_within_limits_wrapping_around_pi() {
+		let anchor = Vec3::new(0., 0., 0.);
+		let mut agent =
+			Transform::from_translation(orbit_position(170., 0.)).looking_at(anchor, UP);
+		let event = MouseMotion {
+			delta: Vec2 {
+				x: radians_from_degrees(-20.),
+				y: 0.,
+			},
+		};
+
+		event.anchored_movement(
+			&mut agent,
+			Anchor(anchor),
+			Seconds(1.),
+			CameraRotationSettings {
+				yaw_limits: Some((radians_from_degrees(135.), radians_from_degrees(225.))),
+				..rotation_settings()
+			},
+		);
+
+		assert_approx_eq!(
+			Transform::from_translation(orbit_position(190., 0.)).looking_at(anchor, UP),
+			agent,
+			TOLERANCE
+		);
+	}
+
+	#[test]
+	fn clamp_yaw_to_limits_wrapping_around_pi() {
+		let anchor = Vec3::new(0., 0., 0.);
+		let mut agent =
+			Transform::from_translation(orbit_position(-170., 0.)).looking_at(anchor, UP);
+		let event = MouseMotion {
+			delta: Vec2 {
+				x: radians_from_degrees(-90.),
+				y: 0.,
+			},
+		};
+
+		event.anchored_movement(
+			&mut agent,
+			Anchor(anchor),
+			Seconds(1.),
+			CameraRotationSettings {
+				yaw_limits: Some((radians_from_degrees(135.), radians_from_degrees(225.))),
+				..rotation_settings()
+			},
+		);
+
+		assert_approx_eq!(
+			Transform::from_translation(orbit_position(225., 0.)).looking_at(anchor, UP),
+			agent,
+			TOLERANCE
+		);
+	}
+
+	#[test]
+	fn turn_orbit_yaw_past_pi() {
+		let anchor = Vec3::new(0., 0., 0.);
+		let mut agent =
+			Transform::from_translation(orbit_position(170., 0.)).looking_at(anchor, UP);
+		let mut orbit = Orbit::from_transform(&agent, Anchor(anchor));
+		let event = MouseMotion {
+			delta: Vec2 {
+				x: radians_from_degrees(-20.),
+				y: 0.,
+			},
+		};
+
+		event.orbit_movement(
+			&mut agent,
+			&mut orbit,
+			Anchor(anchor),
+			Seconds(1.),
+			rotation_settings(),
+		);
+
+		assert_approx_eq!(radians_from_degrees(190.), orbit.yaw, TOLERANCE_F32);
+	}
+
+	#[test]
+	fn do_not_move_agent_on_anchor() {
+		let anchor = Vec3::new(0., 0., 0.);
+		let mut agent = Transform::from_translation(anchor);
+		let event = MouseMotion {
+			delta: Vec2 {
+				x: radians_from_degrees(45.),
+				y: radians_from_degrees(45.),
+			},
+		};
+
+		event.anchored_movement(&mut agent, Anchor(anchor), Seconds(1.), rotation_settings());
+
+		assert_eq!(Transform::from_translation(anchor), agent);
+	}
 }
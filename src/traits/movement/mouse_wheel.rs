@@ -1,35 +1,22 @@
 use super::{Anchor, AnchoredMovement, Seconds};
-use crate::resources::CameraZoomSettings;
+use crate::{components::SmoothZoom, events::ZoomMotion, resources::CameraZoomSettings};
 use bevy::{input::mouse::MouseWheel, prelude::*};
 
 impl AnchoredMovement for MouseWheel {
 	type TExtra = CameraZoomSettings;
+	type TFilter = Without<SmoothZoom>;
 
 	fn anchored_movement(
 		&self,
 		agent: &mut Transform,
-		Anchor(target): Anchor,
-		Seconds(delta): Seconds,
+		around: Anchor,
+		delta: Seconds,
 		settings: CameraZoomSettings,
 	) {
-		let Some(zoom) = get_zoom(self) else {
-			return;
+		let zoom = ZoomMotion {
+			lines: settings.scrolled_lines(self),
 		};
-
-		let direction = agent.translation - target;
-		let distance = direction.length();
-		let zoom = zoom * distance * settings.sensitivity * delta;
-		let zoomed_distance = f32::max(distance - zoom, settings.minimal_distance);
-
-		agent.translation = target + direction.normalize() * zoomed_distance;
-	}
-}
-
-fn get_zoom(wheel: &MouseWheel) -> Option<f32> {
-	match wheel.y {
-		y if y < 0. => Some(-1.),
-		y if y > 0. => Some(1.),
-		_ => None,
+		zoom.anchored_movement(agent, around, delta, settings);
 	}
 }
 
@@ -46,7 +33,7 @@ mod tests {
 
 	fn mouse_wheel(zoom: Zoom) -> MouseWheel {
 		MouseWheel {
-			unit: MouseScrollUnit::Pixel,
+			unit: MouseScrollUnit::Line,
 			x: 0.,
 			y: match zoom {
 				Zoom::Out(distance) => -(distance as f32),
@@ -223,6 +210,7 @@ mod tests {
 			CameraZoomSettings {
 				sensitivity: 1.,
 				minimal_distance: 4.,
+				..default()
 			},
 		);
 
@@ -230,7 +218,26 @@ mod tests {
 	}
 
 	#[test]
-	fn zoom_out_independent_from_zoom_amount() {
+	fn zoom_out_is_limited() {
+		let mut agent = Transform::from_xyz(10., 0., 0.);
+		let event = mouse_wheel(Zoom::Out(1));
+
+		event.anchored_movement(
+			&mut agent,
+			Anchor(Vec3::new(0., 0., 0.)),
+			Seconds(1.),
+			CameraZoomSettings {
+				sensitivity: 1.,
+				maximal_distance: 15.,
+				..default()
+			},
+		);
+
+		assert_eq!(Transform::from_xyz(15., 0., 0.), agent);
+	}
+
+	#[test]
+	fn zoom_out_scaled_by_lines() {
 		let mut agent = Transform::from_xyz(5., 0., 0.);
 		let event = mouse_wheel(Zoom::Out(2));
 
@@ -244,11 +251,11 @@ mod tests {
 			},
 		);
 
-		assert_eq!(Transform::from_xyz(10., 0., 0.), agent);
+		assert_eq!(Transform::from_xyz(15., 0., 0.), agent);
 	}
 
 	#[test]
-	fn zoom_in_independent_from_zoom_amount() {
+	fn zoom_in_scaled_by_lines() {
 		let mut agent = Transform::from_xyz(10., 0., 0.);
 		let event = mouse_wheel(Zoom::In(2));
 
@@ -262,7 +269,29 @@ mod tests {
 			},
 		);
 
-		assert_eq!(Transform::from_xyz(9., 0., 0.), agent);
+		assert_eq!(Transform::from_xyz(8., 0., 0.), agent);
+	}
+
+	#[test]
+	fn zoom_in_scaled_by_pixels_per_line() {
+		let mut agent = Transform::from_xyz(10., 0., 0.);
+		let event = MouseWheel {
+			unit: MouseScrollUnit::Pixel,
+			..mouse_wheel(Zoom::In(10))
+		};
+
+		event.anchored_movement(
+			&mut agent,
+			Anchor(Vec3::new(0., 0., 0.)),
+			Seconds(0.1),
+			CameraZoomSettings {
+				sensitivity: 1.,
+				pixels_per_line: 5.,
+				..default()
+			},
+		);
+
+		assert_eq!(Transform::from_xyz(8., 0., 0.), agent);
 	}
 
 	#[test]
@@ -0,0 +1,54 @@
+use super::{Anchor, AnchoredMovement, Orbit, Seconds};
+use crate::{components::OrbitInertia, events::OrbitMotion, resources::CameraRotationSettings};
+use bevy::{input::mouse::MouseMotion, prelude::*};
+
+impl AnchoredMovement for OrbitMotion {
+	type TExtra = CameraRotationSettings;
+	type TFilter = Without<OrbitInertia>;
+
+	fn anchored_movement(
+		&self,
+		agent: &mut Transform,
+		around: Anchor,
+		delta: Seconds,
+		settings: CameraRotationSettings,
+	) {
+		let motion = MouseMotion { delta: self.delta };
+		motion.anchored_movement(agent, around, delta, settings);
+	}
+
+	fn orbit_movement(
+		&self,
+		agent: &mut Transform,
+		orbit: &mut Orbit,
+		around: Anchor,
+		delta: Seconds,
+		settings: CameraRotationSettings,
+	) {
+		let motion = MouseMotion { delta: self.delta };
+		motion.orbit_movement(agent, orbit, around, delta, settings);
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn orbit_like_mouse_motion() {
+		let settings = CameraRotationSettings::default();
+		let mut agent = Transform::from_xyz(1., 2., 3.).looking_at(Vec3::ZERO, Vec3::Y);
+		let mut expected = agent;
+
+		OrbitMotion {
+			delta: Vec2::new(4., -5.),
+		}
+		.anchored_movement(&mut agent, Anchor(Vec3::ZERO), Seconds(0.1), settings);
+		MouseMotion {
+			delta: Vec2::new(4., -5.),
+		}
+		.anchored_movement(&mut expected, Anchor(Vec3::ZERO), Seconds(0.1), settings);
+
+		assert_eq!(expected, agent);
+	}
+}
@@ -0,0 +1,163 @@
+use super::{Anchor, AnchoredMovement, Seconds};
+use crate::{events::PanMotion, resources::CameraPanSettings};
+use bevy::prelude::*;
+
+impl AnchoredMovement for PanMotion {
+	type TExtra = CameraPanSettings;
+	type TFilter = ();
+
+	fn anchored_movement(
+		&self,
+		agent: &mut Transform,
+		around: Anchor,
+		delta: Seconds,
+		settings: CameraPanSettings,
+	) {
+		agent.translation += self.pan(agent, around, delta, settings);
+	}
+
+	fn moved_anchor(
+		&self,
+		agent: &Transform,
+		around: Anchor,
+		delta: Seconds,
+		settings: CameraPanSettings,
+	) -> Anchor {
+		let Anchor(anchor) = around;
+		Anchor(anchor + self.pan(agent, around, delta, settings))
+	}
+}
+
+impl PanMotion {
+	/// Translation of the agent and its anchor, scaled by their distance to pan the view
+	/// by the same amount at any zoom level.
+	fn pan(
+		&self,
+		agent: &Transform,
+		Anchor(anchor): Anchor,
+		Seconds(delta): Seconds,
+		CameraPanSettings { sensitivity }: CameraPanSettings,
+	) -> Vec3 {
+		let distance = (agent.translation - anchor).length();
+		let pan = agent.left() * self.delta.x + agent.up() * self.delta.y;
+
+		pan * distance * sensitivity * delta
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::assert_approx_eq;
+
+	const TOLERANCE: Vec3 = Vec3::splat(0.000001);
+
+	fn pan(x: f32, y: f32) -> PanMotion {
+		PanMotion {
+			delta: Vec2::new(x, y),
+		}
+	}
+
+	#[test]
+	fn pan_left_when_dragging_right() {
+		let mut agent = Transform::from_xyz(0., 0., 1.).looking_at(Vec3::ZERO, Vec3::Y);
+		let event = pan(1., 0.);
+
+		let Anchor(anchor) = event.moved_anchor(
+			&agent,
+			Anchor(Vec3::ZERO),
+			Seconds(1.),
+			CameraPanSettings { sensitivity: 1. },
+		);
+		event.anchored_movement(
+			&mut agent,
+			Anchor(Vec3::ZERO),
+			Seconds(1.),
+			CameraPanSettings { sensitivity: 1. },
+		);
+
+		assert_approx_eq!(Vec3::new(-1., 0., 1.), agent.translation, TOLERANCE);
+		assert_approx_eq!(Vec3::new(-1., 0., 0.), anchor, TOLERANCE);
+	}
+
+	#[test]
+	fn pan_up_when_dragging_down() {
+		let mut agent = Transform::from_xyz(0., 0., 1.).looking_at(Vec3::ZERO, Vec3::Y);
+		let event = pan(0., 1.);
+
+		let Anchor(anchor) = event.moved_anchor(
+			&agent,
+			Anchor(Vec3::ZERO),
+			Seconds(1.),
+			CameraPanSettings { sensitivity: 1. },
+		);
+		event.anchored_movement(
+			&mut agent,
+			Anchor(Vec3::ZERO),
+			Seconds(1.),
+			CameraPanSettings { sensitivity: 1. },
+		);
+
+		assert_approx_eq!(Vec3::new(0., 1., 1.), agent.translation, TOLERANCE);
+		assert_approx_eq!(Vec3::new(0., 1., 0.), anchor, TOLERANCE);
+	}
+
+	#[test]
+	fn pan_in_view_plane() {
+		let mut agent = Transform::from_xyz(1., 0., 0.).looking_at(Vec3::ZERO, Vec3::Y);
+		let event = pan(1., 0.);
+
+		let Anchor(anchor) = event.moved_anchor(
+			&agent,
+			Anchor(Vec3::ZERO),
+			Seconds(1.),
+			CameraPanSettings { sensitivity: 1. },
+		);
+		event.anchored_movement(
+			&mut agent,
+			Anchor(Vec3::ZERO),
+			Seconds(1.),
+			CameraPanSettings { sensitivity: 1. },
+		);
+
+		assert_approx_eq!(Vec3::new(1., 0., 1.), agent.translation, TOLERANCE);
+		assert_approx_eq!(Vec3::new(0., 0., 1.), anchor, TOLERANCE);
+	}
+
+	#[test]
+	fn pan_scaled_by_distance_sensitivity_and_delta() {
+		let mut agent = Transform::from_xyz(0., 0., 4.).looking_at(Vec3::ZERO, Vec3::Y);
+		let event = pan(1., 0.);
+
+		let Anchor(anchor) = event.moved_anchor(
+			&agent,
+			Anchor(Vec3::ZERO),
+			Seconds(0.5),
+			CameraPanSettings { sensitivity: 0.25 },
+		);
+		event.anchored_movement(
+			&mut agent,
+			Anchor(Vec3::ZERO),
+			Seconds(0.5),
+			CameraPanSettings { sensitivity: 0.25 },
+		);
+
+		assert_approx_eq!(Vec3::new(-0.5, 0., 4.), agent.translation, TOLERANCE);
+		assert_approx_eq!(Vec3::new(-0.5, 0., 0.), anchor, TOLERANCE);
+	}
+
+	#[test]
+	fn keep_orientation() {
+		let mut agent = Transform::from_xyz(0., 0., 4.).looking_at(Vec3::ZERO, Vec3::Y);
+		let rotation = agent.rotation;
+
+		pan(1., 1.).anchored_movement(
+			&mut agent,
+			Anchor(Vec3::ZERO),
+			Seconds(1.),
+			CameraPanSettings { sensitivity: 1. },
+		);
+
+		assert_eq!(rotation, agent.rotation);
+	}
+}
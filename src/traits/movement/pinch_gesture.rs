@@ -0,0 +1,72 @@
+use super::{Anchor, AnchoredMovement, Seconds};
+use crate::{components::SmoothZoom, events::ZoomMotion, resources::CameraZoomSettings};
+use bevy::{input::gestures::PinchGesture, prelude::*};
+
+impl AnchoredMovement for PinchGesture {
+	type TExtra = CameraZoomSettings;
+	type TFilter = Without<SmoothZoom>;
+
+	fn anchored_movement(
+		&self,
+		agent: &mut Transform,
+		around: Anchor,
+		delta: Seconds,
+		settings: CameraZoomSettings,
+	) {
+		let zoom = ZoomMotion {
+			lines: settings.pinched_lines(self),
+		};
+		zoom.anchored_movement(agent, around, delta, settings);
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::assert_approx_eq;
+
+	fn settings() -> CameraZoomSettings {
+		CameraZoomSettings {
+			sensitivity: 1.,
+			minimal_distance: 1.,
+			lines_per_pinch: 4.,
+			..default()
+		}
+	}
+
+	#[test]
+	fn zoom_in_when_spreading_fingers() {
+		let mut agent = Transform::from_xyz(10., 0., 0.);
+
+		PinchGesture(0.25).anchored_movement(
+			&mut agent,
+			Anchor(Vec3::ZERO),
+			Seconds(0.1),
+			settings(),
+		);
+
+		assert_approx_eq!(
+			Vec3::new(9., 0., 0.),
+			agent.translation,
+			Vec3::splat(0.0001)
+		);
+	}
+
+	#[test]
+	fn zoom_out_when_closing_fingers() {
+		let mut agent = Transform::from_xyz(10., 0., 0.);
+
+		PinchGesture(-0.25).anchored_movement(
+			&mut agent,
+			Anchor(Vec3::ZERO),
+			Seconds(0.1),
+			settings(),
+		);
+
+		assert_approx_eq!(
+			Vec3::new(11., 0., 0.),
+			agent.translation,
+			Vec3::splat(0.0001)
+		);
+	}
+}
@@ -0,0 +1,79 @@
+use super::{Anchor, AnchoredMovement, Orbit, Seconds};
+use crate::resources::CameraRotationSettings;
+use bevy::{input::gestures::RotationGesture, prelude::*};
+
+/// Turns the orbit yaw by the twisted angle itself, independent of frame time and
+/// sensitivity, so the scene follows the fingers.
+impl AnchoredMovement for RotationGesture {
+	type TExtra = CameraRotationSettings;
+	type TFilter = ();
+
+	fn anchored_movement(
+		&self,
+		agent: &mut Transform,
+		anchor: Anchor,
+		delta: Seconds,
+		settings: CameraRotationSettings,
+	) {
+		let mut orbit = Orbit::from_transform(agent, anchor);
+		self.orbit_movement(agent, &mut orbit, anchor, delta, settings);
+	}
+
+	fn orbit_movement(
+		&self,
+		agent: &mut Transform,
+		orbit: &mut Orbit,
+		anchor: Anchor,
+		_: Seconds,
+		settings: CameraRotationSettings,
+	) {
+		if orbit.distance == 0. {
+			return;
+		}
+
+		orbit.rotate(self.0, 0., settings);
+		orbit.apply(agent, anchor);
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::assert_approx_eq;
+	use std::f32::consts::PI;
+
+	const TOLERANCE: Vec3 = Vec3::splat(0.00001);
+
+	#[test]
+	fn yaw_by_rotation() {
+		let mut agent = Transform::from_xyz(0., 0., 2.).looking_at(Vec3::ZERO, Vec3::Y);
+
+		RotationGesture(PI / 2.).anchored_movement(
+			&mut agent,
+			Anchor(Vec3::ZERO),
+			Seconds(0.1),
+			CameraRotationSettings::default(),
+		);
+
+		assert_approx_eq!(Vec3::new(2., 0., 0.), agent.translation, TOLERANCE);
+		assert_approx_eq!(Vec3::NEG_X, *agent.forward(), TOLERANCE);
+	}
+
+	#[test]
+	fn yaw_within_limits() {
+		let mut agent = Transform::from_xyz(0., 0., 2.).looking_at(Vec3::ZERO, Vec3::Y);
+
+		RotationGesture(PI / 2.).anchored_movement(
+			&mut agent,
+			Anchor(Vec3::ZERO),
+			Seconds(0.1),
+			CameraRotationSettings {
+				yaw_limits: Some((0., PI / 4.)),
+				..default()
+			},
+		);
+
+		let expected = Quat::from_rotation_y(PI / 4.) * Vec3::new(0., 0., 2.);
+		assert_approx_eq!(expected, agent.translation, TOLERANCE);
+	}
+}
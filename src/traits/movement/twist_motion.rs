@@ -0,0 +1,59 @@
+use super::{Anchor, AnchoredMovement, Orbit, Seconds};
+use crate::{events::TwistMotion, resources::CameraRotationSettings};
+use bevy::{input::gestures::RotationGesture, prelude::*};
+
+impl AnchoredMovement for TwistMotion {
+	type TExtra = CameraRotationSettings;
+	type TFilter = ();
+
+	fn anchored_movement(
+		&self,
+		agent: &mut Transform,
+		around: Anchor,
+		delta: Seconds,
+		settings: CameraRotationSettings,
+	) {
+		let rotation = RotationGesture(self.angle);
+		rotation.anchored_movement(agent, around, delta, settings);
+	}
+
+	fn orbit_movement(
+		&self,
+		agent: &mut Transform,
+		orbit: &mut Orbit,
+		around: Anchor,
+		delta: Seconds,
+		settings: CameraRotationSettings,
+	) {
+		let rotation = RotationGesture(self.angle);
+		rotation.orbit_movement(agent, orbit, around, delta, settings);
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use std::f32::consts::PI;
+
+	#[test]
+	fn twist_like_rotation_gesture() {
+		let settings = CameraRotationSettings::default();
+		let mut agent = Transform::from_xyz(1., 2., 3.).looking_at(Vec3::ZERO, Vec3::Y);
+		let mut expected = agent;
+
+		TwistMotion { angle: PI / 3. }.anchored_movement(
+			&mut agent,
+			Anchor(Vec3::ZERO),
+			Seconds(0.1),
+			settings,
+		);
+		RotationGesture(PI / 3.).anchored_movement(
+			&mut expected,
+			Anchor(Vec3::ZERO),
+			Seconds(0.1),
+			settings,
+		);
+
+		assert_eq!(expected, agent);
+	}
+}
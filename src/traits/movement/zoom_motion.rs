@@ -0,0 +1,54 @@
+use super::{Anchor, AnchoredMovement, Seconds};
+use crate::{components::SmoothZoom, events::ZoomMotion, resources::CameraZoomSettings};
+use bevy::prelude::*;
+
+impl AnchoredMovement for ZoomMotion {
+	type TExtra = CameraZoomSettings;
+	type TFilter = Without<SmoothZoom>;
+
+	fn anchored_movement(
+		&self,
+		agent: &mut Transform,
+		Anchor(target): Anchor,
+		Seconds(delta): Seconds,
+		settings: CameraZoomSettings,
+	) {
+		let direction = agent.translation - target;
+		let distance = direction.length();
+
+		if self.lines == 0. || distance == 0. {
+			return;
+		}
+
+		let zoomed_distance = settings.zoomed_distance(distance, self.lines, delta);
+		agent.translation = target + direction.normalize() * zoomed_distance;
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::assert_approx_eq;
+
+	#[test]
+	fn zoom_in_by_lines() {
+		let mut agent = Transform::from_xyz(10., 0., 0.);
+
+		ZoomMotion { lines: 0.5 }.anchored_movement(
+			&mut agent,
+			Anchor(Vec3::ZERO),
+			Seconds(0.1),
+			CameraZoomSettings {
+				sensitivity: 1.,
+				minimal_distance: 1.,
+				..default()
+			},
+		);
+
+		assert_approx_eq!(
+			Vec3::new(9.5, 0., 0.),
+			agent.translation,
+			Vec3::splat(0.0001)
+		);
+	}
+}